@@ -0,0 +1,227 @@
+/*
+ * Cycles of iterated simulations: a state stepped over and over again, like a platform spun
+ * a billion times, a ghost following its path or a network of modules pressed until rx wakes up.
+ *
+ * A deterministic step from a finite set of states always ends up looping: after `prefix` steps
+ * the states come back every `period` steps. brent and floyd find the loop keeping only a couple
+ * of states and comparing them, hashed remembers every state it meets (or a key of it) and gives
+ * them back, hashed_within gives up past a number of states. align tells when events happening on
+ * several cycles first happen together.
+ */
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// the steps before the loop
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// the step of the prefix or of the first period that gives the same state as step n
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+/// floyd's tortoise and hare, the hare steps twice as fast
+pub fn floyd<S: Clone + PartialEq>(start: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let half = step(&hare);
+        hare = step(&half);
+    }
+
+    // the hare is a multiple of the period ahead, they meet again at the start of the loop
+    let mut prefix = 0;
+    tortoise = start.clone();
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+    Cycle { prefix, period }
+}
+
+/// brent's teleporting tortoise, fewer steps than floyd
+pub fn brent<S: Clone + PartialEq>(start: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // a hare one period ahead meets the tortoise at the start of the loop
+    let mut prefix = 0;
+    tortoise = start.clone();
+    hare = (0..period).fold(start.clone(), |s, _| step(&s));
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    Cycle { prefix, period }
+}
+
+/// the state after n steps, without running them all
+pub fn nth<S: Clone + PartialEq>(start: &S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let cycle = brent(start, &mut step);
+    (0..cycle.reduce(n)).fold(start.clone(), |s, _| step(&s))
+}
+
+/// the cycle and the states of the prefix and of the first period, in order. two states are the
+/// same when they have the same key, a key that tells apart fewer states gives a wrong cycle
+pub fn hashed_by<S, K: Eq + Hash>(
+    start: S,
+    step: impl FnMut(&S) -> S,
+    key: impl FnMut(&S) -> K,
+) -> (Cycle, Vec<S>) {
+    hashed_within(start, step, key, usize::MAX).expect("a loop before usize::MAX states")
+}
+
+/// like hashed_by, but None when the first `max` states are all different: the states are kept
+/// in memory, a step with too many states can not be looped over that way
+pub fn hashed_within<S, K: Eq + Hash>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+    max: usize,
+) -> Option<(Cycle, Vec<S>)> {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+    loop {
+        match seen.entry(key(&state)) {
+            Entry::Occupied(e) => {
+                let prefix = *e.get();
+                return Some((Cycle { prefix, period: states.len() - prefix }, states));
+            }
+            Entry::Vacant(e) => {
+                if states.len() == max {
+                    return None;
+                }
+                e.insert(states.len());
+            }
+        }
+        let next = step(&state);
+        states.push(std::mem::replace(&mut state, next));
+    }
+}
+
+/// the cycle and the states of the prefix and of the first period, in order
+pub fn hashed<S: Clone + Eq + Hash>(start: S, step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    hashed_by(start, step, S::clone)
+}
+
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+// x = a1 mod m1 and x = a2 mod m2 as a single remainder of their lcm, when they agree
+fn chinese((a1, m1): (i128, i128), (a2, m2): (i128, i128)) -> Option<(i128, i128)> {
+    let (g, p, _) = extended_gcd(m1, m2);
+    if (a2 - a1) % g != 0 {
+        return None;
+    }
+    let lcm = m1 / g * m2;
+    let k = ((a2 - a1) / g * p).rem_euclid(m2 / g);
+    Some(((a1 + m1 * k).rem_euclid(lcm), lcm))
+}
+
+/// the first step at which every event happens. an event is given by its cycle and the steps of
+/// the prefix and of the first period it happens at. None when they never happen together
+pub fn align(events: &[(Cycle, Vec<usize>)]) -> Option<usize> {
+    // until every cycle has started looping the steps are checked one by one
+    let start = events.iter().map(|(cycle, _)| cycle.prefix).max().unwrap_or(0);
+    let happens = |n: usize| events.iter().all(|(cycle, steps)| steps.contains(&cycle.reduce(n)));
+    if let Some(n) = (0..start).find(|&n| happens(n)) {
+        return Some(n);
+    }
+
+    // then each event comes back at the same remainders of its period
+    let mut remainders = vec![(0, 1)];
+    for (cycle, steps) in events {
+        let period = cycle.period as i128;
+        remainders = remainders.into_iter().flat_map(|r| {
+            steps.iter()
+            .filter(|&&s| s >= cycle.prefix)
+            .filter_map(move |&s| chinese(r, (s as i128 % period, period)))
+        })
+        .collect();
+    }
+    let start = start as i128;
+    remainders.into_iter()
+    .map(|(a, m)| a + ((start - a).max(0) + m - 1) / m * m)
+    .min()
+    .map(|n| n as usize)
+}
+
+#[test]
+fn test_1() {
+    // 1 -> 2 -> 4 -> 8 -> 16 -> ... -> 76 -> 52 -> 4, 20 numbers from 4 to 52
+    let double = |x: &u32| x * 2 % 100;
+    let cycle = Cycle { prefix: 2, period: 20 };
+    assert_eq!(cycle, floyd(&1, double));
+    assert_eq!(cycle, brent(&1, double));
+    let (hashed_cycle, states) = hashed(1, double);
+    assert_eq!(cycle, hashed_cycle);
+    assert_eq!(vec![1, 2, 4, 8], states[..4]);
+    assert_eq!(22, states.len());
+    let n = 1_000_000_007;
+    assert_eq!(states[cycle.reduce(n)], nth(&1, double, n));
+    assert_eq!((0..30).fold(1, |x, _| double(&x)), nth(&1, double, 30));
+
+    // keyed on the last digit only, 2 and 32 look the same
+    let (cycle, _) = hashed_by(1, double, |x| x % 10);
+    assert_eq!(Cycle { prefix: 1, period: 4 }, cycle);
+
+    // the 22 states are all kept, but not 21
+    assert_eq!(Some(cycle), hashed_within(1, double, |x| x % 10, 5).map(|(c, _)| c));
+    assert!(hashed_within(1, double, |&x| x, 22).is_some());
+    assert!(hashed_within(1, double, |&x| x, 21).is_none());
+}
+
+#[test]
+fn test_2() {
+    let looping = |period, steps: &[usize]| (Cycle { prefix: 0, period }, steps.to_vec());
+    assert_eq!(Some(9), align(&[looping(4, &[1]), looping(6, &[3])]));
+    assert_eq!(Some(0), align(&[looping(4, &[0, 1]), looping(6, &[0])]));
+    assert_eq!(None, align(&[looping(2, &[0]), looping(4, &[1])]));
+
+    // counters that fire on their last press: step 0 is the start, not a press
+    let counter = |period| (Cycle { prefix: 1, period }, vec![period]);
+    assert_eq!(Some(84), align(&[counter(7), counter(12)]));
+
+    // before the loop, a step only happens once
+    let ghost = (Cycle { prefix: 3, period: 2 }, vec![1, 3]);
+    assert_eq!(Some(1), align(&[ghost.clone(), looping(1, &[0])]));
+    assert_eq!(Some(5), align(&[ghost, looping(5, &[0])]));
+}
@@ -0,0 +1,108 @@
+/*
+ * Where the puzzle input of a day comes from, in order of preference:
+ *  - an explicit path, or `-` to read it from stdin
+ *  - $AOC_INPUT_DIR/dayN
+ *  - dayN/assets/input in the workspace
+ */
+
+use std::env;
+use std::fmt::{self, Display};
+use std::fs::{read_to_string, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+// so that the binaries do not need to be started from the workspace root
+fn workspace() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    pub fn resolve(day: u8, path: Option<&str>) -> Source {
+        match path {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(path)),
+            None => match env::var_os(INPUT_DIR_VAR) {
+                Some(dir) => Source::File(PathBuf::from(dir).join(format!("day{}", day))),
+                None => Source::File(workspace().join(format!("day{}/assets/input", day))),
+            },
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let content = match self {
+            Source::Stdin => {
+                let mut content = String::new();
+                io::stdin().read_to_string(&mut content).map(|_| content)
+            }
+            Source::File(path) => read_to_string(path),
+        };
+        content.map_err(|error| InputError { source: self.clone(), error })
+    }
+
+    /// the input as a stream, for the days that read it one line at a time instead of all at once
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::File(path) => match File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(error) => Err(InputError { source: self.clone(), error }),
+            },
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: Source,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cannot read puzzle input from {}: {}", self.source, self.error)?;
+        if self.error.kind() == io::ErrorKind::NotFound {
+            write!(f, " (give its path, `-` for stdin, or set {})", INPUT_DIR_VAR)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// read the input of a day, see Source::resolve
+pub fn read_input(day: u8, path: Option<&str>) -> Result<String, InputError> {
+    Source::resolve(day, path).read()
+}
+
+#[test]
+fn test_1() {
+    assert_eq!(Source::Stdin, Source::resolve(5, Some("-")));
+    assert_eq!(Source::File(PathBuf::from("my/input")), Source::resolve(5, Some("my/input")));
+
+    let missing = Source::resolve(5, Some("does/not/exist")).read().unwrap_err();
+    assert_eq!(io::ErrorKind::NotFound, missing.error.kind());
+    assert!(missing.to_string().contains("does/not/exist"));
+    let missing = Source::resolve(5, Some("does/not/exist")).open().err().unwrap();
+    assert_eq!(io::ErrorKind::NotFound, missing.error.kind());
+
+    let manifest = Source::File(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"));
+    let first = manifest.open().unwrap().lines().next().unwrap().unwrap();
+    assert_eq!("[package]", first);
+}
@@ -0,0 +1,240 @@
+/*
+ * Common interface implemented by every day of the calendar.
+ *
+ * A day parses its puzzle input once and then answers both parts from the parsed form,
+ * so that binaries, tests and the runner all go through the same code path.
+ */
+
+use std::env;
+use std::fmt::{self, Display};
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+pub mod cycle;
+mod input;
+pub mod log;
+pub mod parse;
+pub mod picture;
+pub use input::{read_input, InputError, Source, INPUT_DIR_VAR};
+pub use parse::ParseError;
+pub use picture::Picture;
+
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// parse the raw puzzle input (the content of the file, not its path)
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Self::Answer1;
+
+    fn part_two(input: &Self::Input) -> Self::Answer2;
+
+    /// part one, or an error when the input parsed for both parts cannot be answered by it.
+    /// the runner goes through this one, by default part one always answers
+    fn try_part_one(input: &Self::Input) -> Result<Self::Answer1, ParseError> {
+        Ok(Self::part_one(input))
+    }
+
+    /// part two, or an error, see try_part_one
+    fn try_part_two(input: &Self::Input) -> Result<Self::Answer2, ParseError> {
+        Ok(Self::part_two(input))
+    }
+
+    /// draw the puzzle and its solution: `show` is given the frames in order, each a picture of
+    /// the terminal with ANSI colours. the days that have nothing to show send no frame
+    fn visualize(_input: &Self::Input, _show: &mut dyn FnMut(&str)) {}
+
+    /// pictures of the puzzle worth exporting, each with a name for its file. none by default
+    fn pictures(_input: &Self::Input) -> Vec<(&'static str, Picture)> {
+        Vec::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One = 1,
+    Two = 2,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("part must be 1 or 2, got {}", s)),
+        }
+    }
+}
+
+/// answer one part from an already parsed input
+pub fn answer<S: Solution>(input: &S::Input, part: Part) -> Result<String, ParseError> {
+    match part {
+        Part::One => S::try_part_one(input).map(|a| a.to_string()),
+        Part::Two => S::try_part_two(input).map(|a| a.to_string()),
+    }
+}
+
+/// parse the input and answer one part
+pub fn solve<S: Solution>(input: &str, part: Part) -> Result<String, ParseError> {
+    answer::<S>(&S::parse(input)?, part)
+}
+
+/// parse the input and send the frames of its visualization to `show`
+pub fn visualize<S: Solution>(input: &str, show: &mut dyn FnMut(&str)) -> Result<(), ParseError> {
+    S::visualize(&S::parse(input)?, show);
+    Ok(())
+}
+
+/// parse the input and paint its pictures
+pub fn pictures<S: Solution>(input: &str) -> Result<Vec<(&'static str, Picture)>, ParseError> {
+    Ok(S::pictures(&S::parse(input)?))
+}
+
+/// the answers of a run, with the time spent parsing the input and solving each part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub parse_time: Duration,
+    pub answers: Vec<(Part, String, Duration)>,
+}
+
+/// why a run gave no answers: the input does not parse, or it parses but a part cannot answer it.
+/// the errors are boxed to keep the results of the runs small
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunError {
+    Parse(Box<ParseError>),
+    Part(Part, Box<ParseError>),
+}
+
+impl RunError {
+    /// the same error, for an input read from `file`
+    pub fn in_file(self, file: impl Display) -> RunError {
+        match self {
+            RunError::Parse(e) => RunError::Parse(Box::new(e.in_file(file))),
+            RunError::Part(part, e) => RunError::Part(part, Box::new(e.in_file(file))),
+        }
+    }
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RunError::Parse(e) => write!(f, "{}", e),
+            RunError::Part(part, e) => write!(f, "part {}: {}", part, e),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+/// parse the input once and answer the given parts, timing each phase. the error is the one of
+/// the parse or of the first part that cannot answer
+pub fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<Run, RunError> {
+    let start = Instant::now();
+    let parsed = S::parse(input).map_err(|e| RunError::Parse(Box::new(e)))?;
+    let parse_time = start.elapsed();
+    let answers = parts.iter().map(|&part| {
+        let start = Instant::now();
+        let answer = answer::<S>(&parsed, part).map_err(|e| RunError::Part(part, Box::new(e)))?;
+        Ok((part, answer, start.elapsed()))
+    }).collect::<Result<_, RunError>>()?;
+    Ok(Run { parse_time, answers })
+}
+
+/// entry point shared by the dayN binaries: `dayN [1|2] [input]`, both parts when none is given
+pub fn main<S: Solution>(day: u8) {
+    let (parts, source) = arguments(day);
+    let input = source.read().unwrap_or_else(|e| fail(e));
+    let input = S::parse(&input).unwrap_or_else(|e| fail(e.in_file(&source)));
+    for part in parts {
+        let answer = answer::<S>(&input, part).unwrap_or_else(|e| fail(e.in_file(&source)));
+        print_answer(part, answer);
+    }
+}
+
+/// the parts and the input asked for on the command line of a dayN binary, see main. for the days
+/// that answer without going through their Solution
+pub fn arguments(day: u8) -> (Vec<Part>, Source) {
+    let args: Vec<String> = env::args().collect();
+    let parts = match args.get(1).map(|p| p.parse::<Part>()) {
+        Some(Ok(part)) => vec![part],
+        Some(Err(e)) => fail(e),
+        None => Part::ALL.to_vec(),
+    };
+    (parts, Source::resolve(day, args.get(2).map(String::as_str)))
+}
+
+/// the answer of a part, printed like every dayN binary does
+pub fn print_answer(part: Part, answer: impl Display) {
+    match part {
+        Part::One => println!("first result {}", answer),
+        Part::Two => println!("second result {}", answer),
+    }
+}
+
+/// the end of a dayN binary that cannot go on: the message on stderr and an exit code of 1
+pub fn fail(message: impl Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+/// the arguments of the other modes of a dayN binary, like `day2 query --bag "1 red" input`.
+/// every flag is given to `flag` with the argument after it, its value, and the error of a flag
+/// it does not know or of a value it cannot use ends the binary. the other arguments are
+/// returned in order
+pub fn flags<'a>(
+    args: impl IntoIterator<Item = &'a str>,
+    mut flag: impl FnMut(&str, &'a str) -> Result<(), String>,
+) -> Vec<&'a str> {
+    let mut args = args.into_iter();
+    let mut rest = Vec::new();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with("--") {
+            rest.push(arg);
+            continue;
+        }
+        let value = args.next().unwrap_or_else(|| fail(format!("{} expects a value", arg)));
+        flag(arg, value).unwrap_or_else(|e| fail(e));
+    }
+    rest
+}
+
+/// the value of a flag, or the error that it expects `expected`
+pub fn flag_value<T: FromStr>(flag: &str, value: &str, expected: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("{} expects {}, got {:?}", flag, expected, value))
+}
+
+#[test]
+fn test_1() {
+    let (mut bag, mut most) = ("", 0);
+    let rest = flags(["--bag", "1 red", "input", "--most", "12"], |flag, value| match flag {
+        "--bag" => {
+            bag = value;
+            Ok(())
+        }
+        "--most" => {
+            most = flag_value(flag, value, "a number")?;
+            Ok(())
+        }
+        _ => Err(format!("unknown flag {}", flag)),
+    });
+    assert_eq!((vec!["input"], "1 red", 12), (rest, bag, most));
+    assert_eq!(Err(String::from("--most expects a number, got \"x\"")), flag_value::<u32>("--most", "x", "a number"));
+}
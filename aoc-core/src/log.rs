@@ -0,0 +1,67 @@
+/*
+ * Debug output of the solvers, kept apart from the answers.
+ *
+ * Nothing is written until the verbosity is raised: 1 shows the debug messages (day19 ranges,
+ * day18 vertices...), 2 also shows the traces (every step of day19's interpreter).
+ * Messages go to stderr unless a log file is set.
+ */
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Debug = 1,
+    Trace = 2,
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+static FILE: Mutex<Option<File>> = Mutex::new(None);
+
+pub fn set_verbosity(verbosity: u8) {
+    VERBOSITY.store(verbosity, Ordering::Relaxed);
+}
+
+/// write the messages to `path` instead of stderr
+pub fn set_file(path: &Path) -> io::Result<()> {
+    *FILE.lock().unwrap() = Some(File::create(path)?);
+    Ok(())
+}
+
+pub fn enabled(level: Level) -> bool {
+    VERBOSITY.load(Ordering::Relaxed) >= level as u8
+}
+
+pub fn write(level: Level, message: fmt::Arguments) {
+    let tag = match level {
+        Level::Debug => "debug",
+        Level::Trace => "trace",
+    };
+    // losing a debug message is not worth failing the run
+    let _ = match FILE.lock().unwrap().as_mut() {
+        Some(file) => writeln!(file, "[{}] {}", tag, message),
+        None => writeln!(io::stderr(), "[{}] {}", tag, message),
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            $crate::log::write($crate::log::Level::Debug, format_args!($($arg)*))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            $crate::log::write($crate::log::Level::Trace, format_args!($($arg)*))
+        }
+    };
+}
@@ -0,0 +1,157 @@
+/*
+ * Errors reported by the parsers of the days.
+ *
+ * A parser reads text, it does not know which file the text comes from: errors are located
+ * from the slice of the input that could not be parsed, and the caller adds the file name.
+ * Lines and columns start at 1, columns count characters. They are 0 for an error of the whole
+ * input, like what a part finds missing once the input is parsed.
+ */
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+    // the offending line and the width of the span, to underline it
+    text: String,
+    width: usize,
+}
+
+// byte offset of `span` in `input`, when it is one of its slices
+fn offset(input: &str, span: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let at = span.as_ptr() as usize;
+    if at >= start && at + span.len() <= start + input.len() {
+        at - start
+    } else {
+        input.find(span).unwrap_or(0)
+    }
+}
+
+impl ParseError {
+    /// error on `span`, a slice of `input`. an empty span points in between two characters
+    pub fn at(input: &str, span: &str, expected: impl Into<String>) -> ParseError {
+        let at = offset(input, span);
+        let line_start = input[..at].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[at..].find('\n').map_or(input.len(), |i| at + i);
+        let found = if !span.is_empty() {
+            format!("{:?}", span)
+        } else if at == input.len() {
+            String::from("end of input")
+        } else if at == line_end {
+            String::from("end of line")
+        } else {
+            format!("{:?}", input[at..].chars().next().unwrap())
+        };
+        ParseError {
+            file: None,
+            line: input[..at].matches('\n').count() + 1,
+            column: input[line_start..at].chars().count() + 1,
+            expected: expected.into(),
+            found,
+            text: input[line_start..line_end].trim_end_matches('\r').to_string(),
+            width: span.lines().next().map_or(1, |l| l.chars().count().max(1)),
+        }
+    }
+
+    /// error at the end of `input`, when something is missing
+    pub fn missing(input: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at(input, &input[input.len()..], expected)
+    }
+
+    /// error of the whole input rather than of a place in it
+    pub fn whole(expected: impl Into<String>, found: impl Into<String>) -> ParseError {
+        ParseError {
+            file: None,
+            line: 0,
+            column: 0,
+            expected: expected.into(),
+            found: found.into(),
+            text: String::new(),
+            width: 0,
+        }
+    }
+
+    /// locate an error of a parser that was given `inner`, a slice of `input`
+    pub fn within(self, input: &str, inner: &str) -> ParseError {
+        let at = offset(input, inner);
+        let line_start = input[..at].rfind('\n').map_or(0, |i| i + 1);
+        let column = match self.line {
+            1 => self.column + input[line_start..at].chars().count(),
+            _ => self.column,
+        };
+        let text = match self.line {
+            1 => format!("{}{}", &input[line_start..at], self.text),
+            _ => self.text,
+        };
+        ParseError { line: self.line + input[..at].matches('\n').count(), column, text, ..self }
+    }
+
+    /// name the file the input was read from
+    pub fn in_file(self, file: impl Display) -> ParseError {
+        ParseError { file: Some(file.to_string()), ..self }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.line == 0 {
+            if let Some(file) = &self.file {
+                write!(f, "{}: ", file)?;
+            }
+            return write!(f, "expected {}, found {}", self.expected, self.found);
+        }
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        writeln!(f, "{}:{}: expected {}, found {}", self.line, self.column, self.expected, self.found)?;
+        let margin = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{} |", margin)?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{} | {}{}", margin, " ".repeat(self.column - 1), "^".repeat(self.width))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// parse `span`, a slice of `input`, into a number
+pub fn number<T: FromStr>(input: &str, span: &str, expected: &str) -> Result<T, ParseError> {
+    span.parse().map_err(|_| ParseError::at(input, span, expected))
+}
+
+/// split `span`, a slice of `input`, around the first `separator`
+pub fn split_once<'a>(input: &str, span: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    span.split_once(separator)
+        .ok_or_else(|| ParseError::at(input, &span[span.len()..], format!("{:?}", separator)))
+}
+
+#[test]
+fn test_1() {
+    let input = "Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61 30\n";
+    let span = &input[33..35];
+    let e = ParseError::at(input, span, "a number").in_file("day4/assets/input");
+    assert_eq!((2, 12), (e.line, e.column));
+    assert_eq!("\"x2\"", e.found);
+    assert_eq!(
+        "day4/assets/input:2:12: expected a number, found \"x2\"\n  |\n2 | Card 2: 13 x2 | 61 30\n  |            ^^",
+        e.to_string()
+    );
+
+    let e = split_once(input, input.lines().nth(1).unwrap(), " -> ").unwrap_err();
+    assert_eq!((2, 22, "end of line"), (e.line, e.column, e.found.as_str()));
+    let e = ParseError::missing(input, "a card");
+    assert_eq!((3, 1, "end of input"), (e.line, e.column, e.found.as_str()));
+
+    let block = &input[22..];
+    let e = ParseError::at(block, &block[11..13], "a number").within(input, block);
+    assert_eq!((2, 12), (e.line, e.column));
+
+    let e = ParseError::whole("a node AAA", "none");
+    assert_eq!("expected a node AAA, found none", e.to_string());
+    assert_eq!("day8/assets/input: expected a node AAA, found none", e.in_file("day8/assets/input").to_string());
+}
@@ -0,0 +1,41 @@
+/*
+ * A picture of a puzzle state, one colour per cell of a grid, for `aoc export`.
+ *
+ * the days paint them (aoc-grid turns the layers of a Render into one), the runner writes them
+ * as PNG or SVG files.
+ */
+
+pub type Rgb = (u8, u8, u8);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    width: usize,
+    height: usize,
+    cells: Vec<Rgb>,
+}
+
+impl Picture {
+    pub fn new(width: usize, height: usize, fill: Rgb) -> Picture {
+        Picture { width, height, cells: vec![fill; width * height] }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (i, j): (usize, usize)) -> Rgb {
+        self.cells[i * self.width + j]
+    }
+
+    pub fn set(&mut self, (i, j): (usize, usize), color: Rgb) {
+        self.cells[i * self.width + j] = color;
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[Rgb]> + '_ {
+        self.cells.chunks(self.width.max(1))
+    }
+}
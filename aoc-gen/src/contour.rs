@@ -0,0 +1,86 @@
+// A random simple loop, for the pipes of day10 and the trenches of day18.
+//
+// A random spanning tree of a `rows` x `cols` grid is drawn thick: every node is a 2x2 block of
+// cells and every edge a 2 cells wide bridge, 1 cell of gap in between. A tree has no hole and
+// two blocks never touch by a corner only, so the border of the drawing is a single loop that
+// never touches itself.
+
+use std::collections::HashMap;
+use rand::RngExt;
+use rand_chacha::ChaCha8Rng;
+
+pub type Corner = (usize, usize);
+
+// randomized Prim, the edges are returned as pairs of nodes
+fn spanning_tree(rng: &mut ChaCha8Rng, rows: usize, cols: usize) -> Vec<(Corner, Corner)> {
+    let mut in_tree = vec![vec![false; cols]; rows];
+    let mut frontier = Vec::new();
+    let mut tree = Vec::new();
+    let add = |node: Corner, in_tree: &mut Vec<Vec<bool>>, frontier: &mut Vec<(Corner, Corner)>| {
+        let (i, j) = node;
+        in_tree[i][j] = true;
+        if i > 0 { frontier.push((node, (i - 1, j))) }
+        if i + 1 < rows { frontier.push((node, (i + 1, j))) }
+        if j > 0 { frontier.push((node, (i, j - 1))) }
+        if j + 1 < cols { frontier.push((node, (i, j + 1))) }
+    };
+
+    add((rng.random_range(0..rows), rng.random_range(0..cols)), &mut in_tree, &mut frontier);
+    while !frontier.is_empty() {
+        let (from, to) = frontier.swap_remove(rng.random_range(0..frontier.len()));
+        if !in_tree[to.0][to.1] {
+            tree.push((from, to));
+            add(to, &mut in_tree, &mut frontier);
+        }
+    }
+    tree
+}
+
+/// the corners of the loop, clockwise with the rows pointing down. they lie in a grid of
+/// 3*rows x 3*cols corners
+pub fn random_loop(rng: &mut ChaCha8Rng, rows: usize, cols: usize) -> Vec<Corner> {
+    let (height, width) = (3 * rows - 1, 3 * cols - 1);
+    let mut filled = vec![vec![false; width]; height];
+    for i in 0..rows {
+        for j in 0..cols {
+            for (r, c) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+                filled[3 * i + r][3 * j + c] = true;
+            }
+        }
+    }
+    for ((i1, j1), (i2, j2)) in spanning_tree(rng, rows, cols) {
+        let (i, j) = (i1.min(i2), j1.min(j2));
+        if i1 == i2 {
+            filled[3 * i][3 * j + 2] = true;
+            filled[3 * i + 1][3 * j + 2] = true;
+        } else {
+            filled[3 * i + 2][3 * j] = true;
+            filled[3 * i + 2][3 * j + 1] = true;
+        }
+    }
+
+    // every side of a filled cell that faces an empty one is walked with the cell on its right
+    let is_filled = |r: isize, c: isize| r >= 0 && c >= 0 && (r as usize) < height && (c as usize) < width && filled[r as usize][c as usize];
+    let mut next: HashMap<Corner, Corner> = HashMap::new();
+    for r in 0..height {
+        for c in 0..width {
+            let (ri, ci) = (r as isize, c as isize);
+            if !is_filled(ri, ci) {
+                continue;
+            }
+            if !is_filled(ri - 1, ci) { next.insert((r, c), (r, c + 1)); }
+            if !is_filled(ri, ci + 1) { next.insert((r, c + 1), (r + 1, c + 1)); }
+            if !is_filled(ri + 1, ci) { next.insert((r + 1, c + 1), (r + 1, c)); }
+            if !is_filled(ri, ci - 1) { next.insert((r + 1, c), (r, c)); }
+        }
+    }
+
+    let start = (0, 3 * rng.random_range(0..cols));
+    let mut ret = vec![start];
+    let mut corner = next[&start];
+    while corner != start {
+        ret.push(corner);
+        corner = next[&corner];
+    }
+    ret
+}
@@ -0,0 +1,28 @@
+// day1: `size` lines of letters, digits and spelled out digits. every line has at least one
+// digit for part one, the spelled ones overlap sometimes ("oneight") like in the real input
+
+use rand::RngExt;
+use rand::seq::IndexedRandom;
+use rand_chacha::ChaCha8Rng;
+
+const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+fn line(rng: &mut ChaCha8Rng) -> String {
+    let mut ret = String::new();
+    let len = rng.random_range(5..40);
+    while ret.len() < len {
+        match rng.random_range(0..10) {
+            0 => ret.push(rng.random_range('1'..='9')),
+            1 | 2 => ret.push_str(WORDS.choose(rng).unwrap()),
+            _ => ret.push(rng.random_range('a'..='z')),
+        }
+    }
+    if !ret.chars().any(|c| c.is_ascii_digit()) {
+        ret.insert(rng.random_range(0..=ret.len()), rng.random_range('1'..='9'));
+    }
+    ret
+}
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (0..size).map(|_| line(rng) + "\n").collect()
+}
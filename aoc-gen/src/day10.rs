@@ -0,0 +1,45 @@
+// day10: a maze of `size` x `size` tiles with a single loop of pipes through S, and random pipes
+// everywhere else, inside the loop too. pipes next to S
+// may point at it without being part of the loop
+
+use rand::RngExt;
+use rand::seq::IndexedRandom;
+use rand_chacha::ChaCha8Rng;
+use crate::contour::random_loop;
+
+const JUNK: [char; 7] = ['|', '-', 'L', 'J', '7', 'F', '.'];
+
+// the pipe that connects the tiles before and after `at`
+fn pipe(before: (usize, usize), at: (usize, usize), after: (usize, usize)) -> char {
+    let side = |(i, j): (usize, usize)| match (i as isize - at.0 as isize, j as isize - at.1 as isize) {
+        (-1, 0) => 'N',
+        (1, 0) => 'S',
+        (0, -1) => 'W',
+        _ => 'E',
+    };
+    match (side(before), side(after)) {
+        ('N', 'S') | ('S', 'N') => '|',
+        ('W', 'E') | ('E', 'W') => '-',
+        ('N', 'E') | ('E', 'N') => 'L',
+        ('N', 'W') | ('W', 'N') => 'J',
+        ('S', 'W') | ('W', 'S') => '7',
+        _ => 'F',
+    }
+}
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let side = (size / 3).max(2);
+    let corners = random_loop(rng, side, side);
+    let mut maze: Vec<Vec<char>> = (0..3 * side).map(|_| (0..3 * side).map(|_| *JUNK.choose(rng).unwrap()).collect()).collect();
+    let n = corners.len();
+    for k in 0..n {
+        let (i, j) = corners[k];
+        maze[i][j] = pipe(corners[(k + n - 1) % n], corners[k], corners[(k + 1) % n]);
+    }
+
+    let k = rng.random_range(0..n);
+    let (si, sj) = corners[k];
+    maze[si][sj] = 'S';
+
+    crate::grid(3 * side, 3 * side, |i, j| maze[i][j])
+}
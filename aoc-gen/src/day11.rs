@@ -0,0 +1,13 @@
+// day11: an image of `size` x `size` pixels with a few galaxies, and rows and columns without
+// any to expand
+
+use rand::RngExt;
+use rand_chacha::ChaCha8Rng;
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.random_bool(0.1)).collect();
+    let empty_cols: Vec<bool> = (0..size).map(|_| rng.random_bool(0.1)).collect();
+    crate::grid(size, size, |i, j| {
+        if !empty_rows[i] && !empty_cols[j] && rng.random_bool(0.02) { '#' } else { '.' }
+    })
+}
@@ -0,0 +1,33 @@
+// day12: `size` condition records of 4 to 20 springs, the groups are those of a random row and
+// some of its springs are then hidden behind a '?'. part one tries every combination of the
+// unknown springs, there are at most 16 of them
+
+use rand::RngExt;
+use rand_chacha::ChaCha8Rng;
+
+const MAX_UNKNOWN: usize = 16;
+
+fn record(rng: &mut ChaCha8Rng) -> String {
+    let len = rng.random_range(4..=20);
+    let mut springs: Vec<char> = (0..len).map(|_| if rng.random_bool(0.5) { '#' } else { '.' }).collect();
+    if !springs.contains(&'#') {
+        springs[rng.random_range(0..len)] = '#';
+    }
+    let groups: Vec<String> = springs.split(|&c| c == '.')
+    .filter(|g| !g.is_empty())
+    .map(|g| g.len().to_string())
+    .collect();
+
+    let mut unknown = 0;
+    for spring in springs.iter_mut() {
+        if unknown < MAX_UNKNOWN && rng.random_bool(0.5) {
+            *spring = '?';
+            unknown += 1;
+        }
+    }
+    format!("{} {}", springs.iter().collect::<String>(), groups.join(","))
+}
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (0..size).map(|_| record(rng) + "\n").collect()
+}
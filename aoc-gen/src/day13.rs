@@ -0,0 +1,73 @@
+// day13: `size` patterns of ash and rocks, each with exactly one line of reflection, and exactly
+// one other line that a single smudge breaks. both lines are built in: the pattern is mirrored
+// across the two, then a cell that the first line does not reflect is flipped on one side of
+// the second. the patterns with any other line that is (nearly) a reflection are thrown away
+
+use rand::RngExt;
+use rand_chacha::ChaCha8Rng;
+
+type Pattern = Vec<Vec<bool>>;
+
+fn transpose(p: &Pattern) -> Pattern {
+    (0..p[0].len()).map(|j| p.iter().map(|row| row[j]).collect()).collect()
+}
+
+// the number of cells that differ across each horizontal line, between rows i-1 and i
+fn differences(p: &Pattern) -> Vec<(usize, usize)> {
+    (1..p.len()).map(|i| {
+        let diff = (0..i.min(p.len() - i))
+        .map(|k| p[i - 1 - k].iter().zip(&p[i + k]).filter(|(a, b)| a != b).count())
+        .sum();
+        (i, diff)
+    })
+    .collect()
+}
+
+// the mirror of row `i` across the line above row `line`
+fn mirror(i: usize, line: usize, len: usize) -> Option<usize> {
+    (2 * line).checked_sub(i + 1).filter(|&m| m < len)
+}
+
+fn pattern(rng: &mut ChaCha8Rng) -> Pattern {
+    loop {
+        let (h, w) = (rng.random_range(5..=17), rng.random_range(5..=17));
+        // the reflection does not go through the middle, some rows are left out of it
+        let line = rng.random_range(1..h);
+        if 2 * line == h {
+            continue;
+        }
+        let smudge_line = rng.random_range(1..w);
+
+        let mut p: Pattern = (0..h).map(|_| (0..w).map(|_| rng.random_bool(0.5)).collect()).collect();
+        for row in p.iter_mut() {
+            for j in 0..w {
+                if let Some(m) = mirror(j, smudge_line, w).filter(|&m| m < j) {
+                    row[j] = row[m];
+                }
+            }
+        }
+        for i in 0..h {
+            if let Some(m) = mirror(i, line, h).filter(|&m| m < i) {
+                p[i] = p[m].clone();
+            }
+        }
+        let rows: Vec<usize> = (0..h).filter(|&i| mirror(i, line, h).is_none()).collect();
+        let cols: Vec<usize> = (0..w).filter(|&j| mirror(j, smudge_line, w).is_some()).collect();
+        let (i, j) = (rows[rng.random_range(0..rows.len())], cols[rng.random_range(0..cols.len())]);
+        p[i][j] = !p[i][j];
+
+        let diffs: Vec<usize> = differences(&p).into_iter().chain(differences(&transpose(&p))).map(|(_, d)| d).collect();
+        if diffs.iter().filter(|&&d| d == 0).count() == 1 && diffs.iter().filter(|&&d| d == 1).count() == 1 {
+            return if rng.random_bool(0.5) { transpose(&p) } else { p };
+        }
+    }
+}
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (0..size).map(|_| {
+        let p = pattern(rng);
+        crate::grid(p[0].len(), p.len(), |i, j| if p[i][j] { '#' } else { '.' })
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
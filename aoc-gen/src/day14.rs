@@ -0,0 +1,12 @@
+// day14: a platform of `size` x `size` cells with rounded and cube-shaped rocks
+
+use rand::RngExt;
+use rand_chacha::ChaCha8Rng;
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    crate::grid(size, size, |_, _| match rng.random_range(0..100) {
+        0..=19 => 'O',
+        20..=29 => '#',
+        _ => '.',
+    })
+}
@@ -0,0 +1,22 @@
+// day15: an initialization sequence of `size` steps, on few enough labels that lenses get
+// replaced and removed
+
+use rand::RngExt;
+use rand_chacha::ChaCha8Rng;
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let labels: Vec<String> = (0..size / 4 + 1).map(|_| {
+        (0..rng.random_range(2..=6)).map(|_| rng.random_range('a'..='z')).collect()
+    })
+    .collect();
+    let steps: Vec<String> = (0..size).map(|_| {
+        let label = &labels[rng.random_range(0..labels.len())];
+        if rng.random_bool(0.3) {
+            format!("{}-", label)
+        } else {
+            format!("{}={}", label, rng.random_range(1..=9))
+        }
+    })
+    .collect();
+    steps.join(",") + "\n"
+}
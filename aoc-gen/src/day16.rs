@@ -0,0 +1,11 @@
+// day16: a contraption of `size` x `size` tiles, mostly empty space with mirrors and splitters
+
+use rand::RngExt;
+use rand::seq::IndexedRandom;
+use rand_chacha::ChaCha8Rng;
+
+const DEVICES: [char; 4] = ['/', '\\', '|', '-'];
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    crate::grid(size, size, |_, _| if rng.random_bool(0.1) { *DEVICES.choose(rng).unwrap() } else { '.' })
+}
@@ -0,0 +1,9 @@
+// day17: a map of `size` x `size` heat losses, at least 5 blocks wide for the ultra crucibles
+
+use rand::RngExt;
+use rand_chacha::ChaCha8Rng;
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let size = size.max(5);
+    crate::grid(size, size, |_, _| rng.random_range('1'..='9'))
+}
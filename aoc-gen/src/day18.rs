@@ -0,0 +1,50 @@
+// day18: a dig plan of about `size` instructions. both parts dig the same random loop, clockwise
+// as the solvers expect: part one with 2 to 10 meters between two trenches, part two as far
+// apart as the 5 hex digits of the colours allow
+
+use rand::RngExt;
+use rand_chacha::ChaCha8Rng;
+use crate::contour::random_loop;
+
+// where each line of corners ends up, two lines are at least `min` meters apart
+fn spacing(rng: &mut ChaCha8Rng, lines: usize, min: u64, max: u64) -> Vec<u64> {
+    let mut at = 0;
+    (0..lines).map(|_| {
+        at += rng.random_range(min..=max);
+        at
+    })
+    .collect()
+}
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    // the loop around a tree of n nodes turns about 1.6 * n times
+    let side = (size as f64 / 1.6).sqrt().max(2.0) as usize;
+    let mut corners = random_loop(rng, side, side);
+    let small = (spacing(rng, 3 * side, 2, 10), spacing(rng, 3 * side, 2, 10));
+    let max = 0xfffff / (3 * side as u64);
+    let large = (spacing(rng, 3 * side, max / 10, max), spacing(rng, 3 * side, max / 10, max));
+
+    // only the corners where the loop turns start an instruction
+    let n = corners.len();
+    let turns = |k: usize, c: &[(usize, usize)]| {
+        let (a, b, d) = (c[(k + n - 1) % n], c[k], c[(k + 1) % n]);
+        (b.0 as isize - a.0 as isize, b.1 as isize - a.1 as isize) != (d.0 as isize - b.0 as isize, d.1 as isize - b.1 as isize)
+    };
+    let first = (0..n).find(|&k| turns(k, &corners)).unwrap();
+    corners.rotate_left(first);
+    let mut turns: Vec<(usize, usize)> = (0..n).filter(|&k| turns(k, &corners)).map(|k| corners[k]).collect();
+    turns.push(turns[0]);
+
+    turns.windows(2).map(|w| {
+        let ((i1, j1), (i2, j2)) = (w[0], w[1]);
+        let length = |(rows, cols): &(Vec<u64>, Vec<u64>)| rows[i1].abs_diff(rows[i2]) + cols[j1].abs_diff(cols[j2]);
+        let (letter, digit) = match (i2.cmp(&i1), j2.cmp(&j1)) {
+            (_, std::cmp::Ordering::Greater) => ('R', 0),
+            (std::cmp::Ordering::Greater, _) => ('D', 1),
+            (_, std::cmp::Ordering::Less) => ('L', 2),
+            _ => ('U', 3),
+        };
+        format!("{} {} (#{:05x}{})\n", letter, length(&small), length(&large), digit)
+    })
+    .collect()
+}
@@ -0,0 +1,54 @@
+// day19: `size` workflows in a tree below "in", every rule leading to another workflow or to
+// A or R, and 200 parts to sort
+
+use std::collections::{HashSet, VecDeque};
+use rand::RngExt;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand_chacha::ChaCha8Rng;
+
+const PARTS: usize = 200;
+
+fn name(rng: &mut ChaCha8Rng, taken: &mut HashSet<String>) -> String {
+    loop {
+        let name: String = (0..rng.random_range(2..=3)).map(|_| rng.random_range('a'..='z')).collect();
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut taken: HashSet<String> = [String::from("in")].into();
+    let mut budget = size.max(1) - 1;
+    let mut pending = VecDeque::from([String::from("in")]);
+    let mut workflows = Vec::new();
+
+    while let Some(workflow) = pending.pop_front() {
+        let mut target = |rng: &mut ChaCha8Rng| {
+            if budget > 0 && rng.random_bool(0.6) {
+                budget -= 1;
+                let next = name(rng, &mut taken);
+                pending.push_back(next.clone());
+                next
+            } else {
+                String::from(*["A", "R"].choose(rng).unwrap())
+            }
+        };
+        let mut rules: Vec<String> = (0..rng.random_range(1..=3)).map(|_| {
+            let category = ['x', 'm', 'a', 's'].choose(rng).unwrap();
+            let op = if rng.random_bool(0.5) { '<' } else { '>' };
+            format!("{}{}{}:{}", category, op, rng.random_range(2..4000), target(rng))
+        })
+        .collect();
+        rules.push(target(rng));
+        workflows.push(format!("{}{{{}}}", workflow, rules.join(",")));
+    }
+    workflows.shuffle(rng);
+
+    let parts: Vec<String> = (0..PARTS).map(|_| {
+        let [x, m, a, s] = [(); 4].map(|_| rng.random_range(1..=4000));
+        format!("{{x={},m={},a={},s={}}}", x, m, a, s)
+    })
+    .collect();
+    format!("{}\n\n{}\n", workflows.join("\n"), parts.join("\n"))
+}
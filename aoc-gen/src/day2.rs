@@ -0,0 +1,23 @@
+// day2: `size` games of 1 to 6 reveals, each showing up to 16 cubes of some of the colours
+
+use rand::RngExt;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+
+fn reveal(rng: &mut ChaCha8Rng) -> String {
+    let mut colours = ["red", "green", "blue"];
+    colours.shuffle(rng);
+    let shown = rng.random_range(1..=3);
+    colours[..shown].iter()
+    .map(|colour| format!("{} {}", rng.random_range(1..=16), colour))
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (1..=size).map(|id| {
+        let reveals: Vec<String> = (0..rng.random_range(1..=6)).map(|_| reveal(rng)).collect();
+        format!("Game {}: {}\n", id, reveals.join("; "))
+    })
+    .collect()
+}
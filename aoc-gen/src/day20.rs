@@ -0,0 +1,57 @@
+// day20: the network of the real puzzle with `size` counters, at most 5 for the answer of part
+// two to fit in an u64. the broadcaster starts each of them: 12 flip-flops that count button
+// presses in binary, and a conjunction that fires and resets them when they reach a given odd
+// number. it goes through an inverter to the conjunction in front of rx, which then sees all the
+// counters fire together after the least common multiple of their numbers
+
+use std::collections::HashSet;
+use rand::RngExt;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+
+const BITS: usize = 12;
+
+fn name(rng: &mut ChaCha8Rng, taken: &mut HashSet<String>) -> String {
+    loop {
+        let name: String = (0..2).map(|_| rng.random_range('a'..='z')).collect();
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut taken: HashSet<String> = ["rx"].map(String::from).into();
+    let last = name(rng, &mut taken);
+    let mut modules = vec![format!("&{} -> rx", last)];
+    let mut starts = Vec::new();
+
+    for _ in 0..size.clamp(1, 5) {
+        let count: usize = rng.random_range(1 << (BITS - 1)..1 << BITS) | 1;
+        let flip_flops: Vec<String> = (0..BITS).map(|_| name(rng, &mut taken)).collect();
+        let (hub, inverter) = (name(rng, &mut taken), name(rng, &mut taken));
+
+        // the bits set in the count are watched by the hub, the others are set by it
+        let mut hub_outputs = vec![flip_flops[0].clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut outputs = Vec::new();
+            if bit + 1 < BITS {
+                outputs.push(flip_flops[bit + 1].clone());
+            }
+            if count & (1 << bit) != 0 {
+                outputs.push(hub.clone());
+            } else {
+                hub_outputs.push(flip_flop.clone());
+            }
+            outputs.shuffle(rng);
+            modules.push(format!("%{} -> {}", flip_flop, outputs.join(", ")));
+        }
+        hub_outputs.push(inverter.clone());
+        hub_outputs.shuffle(rng);
+        modules.push(format!("&{} -> {}", hub, hub_outputs.join(", ")));
+        modules.push(format!("&{} -> {}", inverter, last));
+        starts.push(flip_flops[0].clone());
+    }
+    modules.shuffle(rng);
+    format!("broadcaster -> {}\n{}\n", starts.join(", "), modules.join("\n"))
+}
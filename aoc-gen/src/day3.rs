@@ -0,0 +1,31 @@
+// day3: an engine schematic of `size` x `size` cells, with numbers of 1 to 3 digits and symbols
+
+use rand::RngExt;
+use rand::seq::IndexedRandom;
+use rand_chacha::ChaCha8Rng;
+
+const SYMBOLS: [char; 10] = ['*', '*', '*', '#', '+', '$', '/', '@', '%', '='];
+
+fn row(rng: &mut ChaCha8Rng, width: usize) -> String {
+    let mut ret = String::with_capacity(width + 1);
+    let mut len = 0;
+    while len < width {
+        let roll = rng.random_range(0..100);
+        let digits = rng.random_range(1..=3).min(width - len);
+        // two numbers are never next to each other, they would make a single one
+        if roll < 12 && !ret.ends_with(|c: char| c.is_ascii_digit()) {
+            ret.push(rng.random_range('1'..='9'));
+            (1..digits).for_each(|_| ret.push(rng.random_range('0'..='9')));
+            len += digits;
+        } else {
+            ret.push(if roll < 18 { *SYMBOLS.choose(rng).unwrap() } else { '.' });
+            len += 1;
+        }
+    }
+    ret.push('\n');
+    ret
+}
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (0..size).map(|_| row(rng, size)).collect()
+}
@@ -0,0 +1,36 @@
+// day4: `size` scratchcards of 10 winning numbers and 25 numbers we have. the number of matches
+// is kept low enough for the count of cards of part two to stay close to the real inputs
+
+use rand::RngExt;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+
+fn numbers(numbers: &[u32]) -> String {
+    numbers.iter().map(|n| format!("{:>2}", n)).collect::<Vec<_>>().join(" ")
+}
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut copies = vec![1u64; size];
+    let mut total = size as u64;
+    (0..size).map(|i| {
+        let mut matches = match rng.random_range(0..4) {
+            0 | 1 => 0,
+            _ => rng.random_range(1..=10).min(size - 1 - i),
+        };
+        if total + matches as u64 * copies[i] > 1_000_000_000 {
+            matches = 0;
+        }
+        total += matches as u64 * copies[i];
+        for j in i + 1..i + 1 + matches {
+            copies[j] += copies[i];
+        }
+
+        let mut pool: Vec<u32> = (1..100).collect();
+        pool.shuffle(rng);
+        let wins = &pool[..10];
+        let mut have: Vec<u32> = wins[..matches].iter().chain(&pool[10..35 - matches]).copied().collect();
+        have.shuffle(rng);
+        format!("Card {:>3}: {} | {}\n", i + 1, numbers(wins), numbers(&have))
+    })
+    .collect()
+}
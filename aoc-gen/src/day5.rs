@@ -0,0 +1,47 @@
+// day5: an almanac whose 10 seed ranges cover `size` seeds in total.
+// each of the 7 maps cuts the 32 bits numbers in 10 to 40 ranges and moves all of them
+
+use rand::RngExt;
+use rand_chacha::ChaCha8Rng;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil", "soil-to-fertilizer", "fertilizer-to-water", "water-to-light",
+    "light-to-temperature", "temperature-to-humidity", "humidity-to-location",
+];
+
+const SPACE: u64 = 1 << 32;
+
+fn map(rng: &mut ChaCha8Rng, name: &str) -> String {
+    let mut cuts: Vec<u64> = (1..rng.random_range(10..=40)).map(|_| rng.random_range(1..SPACE)).collect();
+    cuts.extend([0, SPACE]);
+    cuts.sort();
+    cuts.dedup();
+    let mut ret = format!("{} map:\n", name);
+    for range in cuts.windows(2) {
+        let length = range[1] - range[0];
+        ret += &format!("{} {} {}\n", rng.random_range(0..SPACE - length), range[0], length);
+    }
+    ret
+}
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let size = size.max(1) as u64;
+    let pairs = size.min(10);
+    let mut lengths: Vec<u64> = (1..pairs).map(|_| rng.random_range(1..size)).collect();
+    lengths.extend([0, size]);
+    lengths.sort();
+    let seeds: Vec<String> = lengths.windows(2)
+    .filter(|w| w[1] > w[0])
+    .map(|w| {
+        let length = w[1] - w[0];
+        format!("{} {}", rng.random_range(0..SPACE - length), length)
+    })
+    .collect();
+
+    let mut ret = format!("seeds: {}\n", seeds.join(" "));
+    for name in MAPS {
+        ret += "\n";
+        ret += &map(rng, name);
+    }
+    ret
+}
@@ -0,0 +1,30 @@
+// day6: `size` races, at most 4 because part two joins their numbers into a single race that
+// must still fit in an i64. every record can be beaten, the one of part two too
+
+use rand::RngExt;
+use rand_chacha::ChaCha8Rng;
+
+fn join(numbers: &[i64]) -> i64 {
+    numbers.iter().map(|n| n.to_string()).collect::<String>().parse().unwrap()
+}
+
+fn beatable(time: i64, distance: i64) -> bool {
+    (time / 2) * (time - time / 2) > distance
+}
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let races = size.clamp(1, 4);
+    loop {
+        let times: Vec<i64> = (0..races).map(|_| rng.random_range(7..100)).collect();
+        let distances: Vec<i64> = times.iter().map(|&t| {
+            let best = (t / 2) * (t - t / 2);
+            rng.random_range(best / 2..best)
+        })
+        .collect();
+        if beatable(join(&times), join(&distances)) {
+            let times: Vec<String> = times.iter().map(|t| format!("{:>4}", t)).collect();
+            let distances: Vec<String> = distances.iter().map(|d| format!("{:>4}", d)).collect();
+            return format!("Time:    {}\nDistance:{}\n", times.join(" "), distances.join(" "));
+        }
+    }
+}
@@ -0,0 +1,18 @@
+// day7: `size` hands of camel cards with their bids
+
+use rand::RngExt;
+use rand::seq::IndexedRandom;
+use rand_chacha::ChaCha8Rng;
+
+const CARDS: [char; 13] = ['A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2'];
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (0..size).map(|_| {
+        // a few cards only, or there would hardly be any pair
+        let kinds = rng.random_range(2..=6);
+        let cards: Vec<char> = CARDS.sample(rng, kinds).copied().collect();
+        let hand: String = (0..5).map(|_| *cards.choose(rng).unwrap()).collect();
+        format!("{} {}\n", hand, rng.random_range(1..=1000))
+    })
+    .collect()
+}
@@ -0,0 +1,62 @@
+// day8: a path of `size` L/R instructions and 6 ghosts. every ghost walks a ring of k * size
+// nodes back to its Z node, k being a different prime for each of them, so that they meet after
+// size * the product of the primes. the wrong turns lead to dead ends that are never visited.
+// AAA is the first ghost, ZZZ the end of its ring
+
+use std::collections::HashSet;
+use rand::RngExt;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+
+const PRIMES: [usize; 6] = [3, 5, 7, 11, 13, 17];
+const NAME: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// a name that is not taken, which ends with `last` or with anything but A and Z
+fn name(rng: &mut ChaCha8Rng, taken: &mut HashSet<String>, last: Option<u8>) -> String {
+    loop {
+        let mut name: Vec<u8> = (0..3).map(|_| NAME[rng.random_range(0..NAME.len())]).collect();
+        match last {
+            Some(last) => name[2] = last,
+            None if name[2] == b'A' || name[2] == b'Z' => continue,
+            None => {}
+        }
+        let name = String::from_utf8(name).unwrap();
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    // there are not enough names for larger rings
+    let size = size.clamp(1, 700);
+    let path: Vec<bool> = (0..size).map(|_| rng.random_bool(0.5)).collect();
+    let mut primes = PRIMES;
+    primes.shuffle(rng);
+
+    let mut taken: HashSet<String> = ["AAA", "ZZZ"].map(String::from).into();
+    let mut nodes = Vec::new();
+    let dead_end = name(rng, &mut taken, None);
+    nodes.push(format!("{} = ({}, {})", dead_end, dead_end, dead_end));
+    for (ghost, k) in primes.into_iter().enumerate() {
+        let len = k * size;
+        let mut ring: Vec<String> = (1..len).map(|_| name(rng, &mut taken, None)).collect();
+        let (start, end) = match ghost {
+            0 => (String::from("AAA"), String::from("ZZZ")),
+            _ => (name(rng, &mut taken, Some(b'A')), name(rng, &mut taken, Some(b'Z'))),
+        };
+        ring.insert(0, start);
+        ring.push(end);
+
+        // node i is always left at step i of the path, the end goes back to the node after start
+        for i in 0..len + 1 {
+            let next = if i == len { &ring[1] } else { &ring[i + 1] };
+            let (left, right) = if path[i % size] { (next, &dead_end) } else { (&dead_end, next) };
+            nodes.push(format!("{} = ({}, {})", ring[i], left, right));
+        }
+    }
+    nodes.shuffle(rng);
+
+    let path: String = path.iter().map(|&left| if left { 'L' } else { 'R' }).collect();
+    format!("{}\n\n{}\n", path, nodes.join("\n"))
+}
@@ -0,0 +1,27 @@
+// day9: `size` reports of 21 values, each a polynomial of degree 0 to 8 built from the bottom of
+// its difference table
+
+use rand::RngExt;
+use rand_chacha::ChaCha8Rng;
+
+const VALUES: usize = 21;
+
+fn report(rng: &mut ChaCha8Rng) -> String {
+    let degree = rng.random_range(0..=8);
+    let mut values = vec![rng.random_range(-5i64..=5); VALUES];
+    for _ in 0..degree {
+        // the first value of the level above, then the running sum of this level
+        let mut acc = rng.random_range(-20..=20);
+        values = values.iter().map(|d| {
+            let v = acc;
+            acc += d;
+            v
+        })
+        .collect();
+    }
+    values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(" ")
+}
+
+pub fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (0..size).map(|_| report(rng) + "\n").collect()
+}
@@ -0,0 +1,96 @@
+/*
+ * Synthetic puzzle inputs, to stress-test and benchmark the solvers without sharing the real ones.
+ *
+ * Every day has a generator that writes a valid input in the format of its puzzle, with the
+ * properties the solvers rely on (a single loop for day10, a single smudge per pattern for
+ * day13, counters feeding rx for day20...). The output only depends on the seed and the size,
+ * the meaning of the size is given by each generator and DEFAULT_SIZES is close to the real inputs.
+ */
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+mod contour;
+mod day1;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+
+pub type Generator = fn(&mut ChaCha8Rng, usize) -> String;
+
+// the size of each day, in the order of the calendar
+pub const DEFAULT_SIZES: [usize; 20] = [
+    1000, 100, 140, 200, 1_000_000, 4, 1000, 100, 200, 140,
+    140, 1000, 100, 100, 4000, 110, 141, 700, 550, 4,
+];
+
+pub fn generator(day: u8) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => day1::generate,
+        2 => day2::generate,
+        3 => day3::generate,
+        4 => day4::generate,
+        5 => day5::generate,
+        6 => day6::generate,
+        7 => day7::generate,
+        8 => day8::generate,
+        9 => day9::generate,
+        10 => day10::generate,
+        11 => day11::generate,
+        12 => day12::generate,
+        13 => day13::generate,
+        14 => day14::generate,
+        15 => day15::generate,
+        16 => day16::generate,
+        17 => day17::generate,
+        18 => day18::generate,
+        19 => day19::generate,
+        20 => day20::generate,
+        _ => return None,
+    };
+    Some(generator)
+}
+
+/// the input of `day` for this seed, of the default size when none is given
+pub fn generate(day: u8, seed: u64, size: Option<usize>) -> Option<String> {
+    let generator = generator(day)?;
+    let size = size.unwrap_or(DEFAULT_SIZES[day as usize - 1]);
+    Some(generator(&mut ChaCha8Rng::seed_from_u64(seed), size))
+}
+
+// a grid of `width` x `height` cells, one line per row
+fn grid(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> char) -> String {
+    let mut ret = String::with_capacity((width + 1) * height);
+    for i in 0..height {
+        ret.extend((0..width).map(|j| cell(i, j)));
+        ret.push('\n');
+    }
+    ret
+}
+
+#[test]
+fn test_1() {
+    for day in 1..=20 {
+        let input = generate(day, 7, Some(5)).unwrap();
+        assert!(!input.is_empty());
+        assert_eq!(input, generate(day, 7, Some(5)).unwrap());
+    }
+    assert_ne!(generate(7, 1, None), generate(7, 2, None));
+    assert!(generate(21, 0, None).is_none());
+}
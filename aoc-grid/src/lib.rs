@@ -0,0 +1,263 @@
+/*
+ * A rectangular 2D grid, the data structure behind most of the puzzles.
+ *
+ * Positions are (row, col) pairs, row 0 is the first line of the input. Every move or
+ * neighbour lookup is bounds checked and returns None (or nothing) outside of the grid.
+ */
+
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+use aoc_core::ParseError;
+
+mod direction;
+pub mod render;
+pub mod search;
+pub use direction::Direction;
+
+pub type Pos = (usize, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+const DIAGONALS: [(isize, isize); 4] = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// every row must have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+        assert!(rows.iter().all(|r| r.len() == width), "rows have different lengths");
+        Grid { width, height, cells: rows.into_iter().flatten().collect() }
+    }
+
+    /// one row per line, one cell per character. `cell` returns None for the characters that
+    /// are not `expected`, and every row must have the same length
+    pub fn parse(input: &str, cell: impl Fn(char) -> Option<T>, expected: &str) -> Result<Grid<T>, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for line in input.lines() {
+            let mut row = Vec::new();
+            for (j, c) in line.char_indices() {
+                let width = rows.first().map_or(usize::MAX, |r| r.len());
+                if row.len() == width {
+                    return Err(ParseError::at(input, &line[j..], format!("a row of {} cells", width)));
+                }
+                row.push(cell(c).ok_or_else(|| ParseError::at(input, &line[j..j + c.len_utf8()], expected))?);
+            }
+            if let Some(first) = rows.first() {
+                if row.len() < first.len() {
+                    return Err(ParseError::at(input, &line[line.len()..], format!("a row of {} cells", first.len())));
+                }
+            }
+            rows.push(row);
+        }
+        if rows.first().is_none_or(|r| r.is_empty()) {
+            return Err(ParseError::missing(input, "a grid"));
+        }
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (i, j): Pos) -> bool {
+        i < self.height && j < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) { Some(&self.cells[pos.0 * self.width + pos.1]) } else { None }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) { Some(&mut self.cells[pos.0 * self.width + pos.1]) } else { None }
+    }
+
+    /// move by a (row, col) offset, None when leaving the grid
+    pub fn offset(&self, (i, j): Pos, (di, dj): (isize, isize)) -> Option<Pos> {
+        let pos = (i.checked_add_signed(di)?, j.checked_add_signed(dj)?);
+        if self.contains(pos) { Some(pos) } else { None }
+    }
+
+    /// move one step in a direction, None when leaving the grid
+    pub fn step(&self, pos: Pos, dir: Direction) -> Option<Pos> {
+        self.offset(pos, dir.offset())
+    }
+
+    /// up, right, down and left neighbours that are inside the grid
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL.into_iter().filter_map(move |d| self.step(pos, d))
+    }
+
+    /// same as neighbours4 plus the diagonals
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours4(pos).chain(DIAGONALS.into_iter().filter_map(move |o| self.offset(pos, o)))
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.cells[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|i| self.row(i))
+    }
+
+    pub fn col(&self, j: usize) -> impl Iterator<Item = &T> + '_ {
+        (0..self.height).map(move |i| &self[(i, j)])
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|j| self.col(j))
+    }
+
+    /// all positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |i| (0..width).map(move |j| (i, j)))
+    }
+
+    /// all cells with their position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter()
+    }
+
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, c)| predicate(c)).map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// rows become columns
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.build(self.height, self.width, |(i, j)| (j, i))
+    }
+
+    /// the first column becomes the first row, read from the bottom
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.build(self.height, self.width, |(i, j)| (self.height - 1 - j, i))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.build(self.height, self.width, |(i, j)| (j, self.width - 1 - i))
+    }
+
+    // new grid where each cell is taken from `from(pos)` in self
+    fn build(&self, width: usize, height: usize, from: impl Fn(Pos) -> Pos) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..height).flat_map(|i| (0..width).map(move |j| (i, j))).map(|p| self[from(p)].clone()).collect();
+        Grid { width, height, cells }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("{:?} is outside of a {}x{} grid", pos, self.height, self.width))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(pos).unwrap_or_else(|| panic!("{:?} is outside of a {}x{} grid", pos, height, width))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for c in row {
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_1() {
+    let grid = Grid::parse("abc\ndef", Some, "a letter").unwrap();
+    assert_eq!((3, 2), (grid.width(), grid.height()));
+    assert_eq!('f', grid[(1, 2)]);
+    assert_eq!(None, grid.get((2, 0)));
+    assert_eq!(vec!['b', 'e'], grid.col(1).copied().collect::<Vec<_>>());
+    assert_eq!("ad\nbe\ncf\n", grid.transpose().to_string());
+    assert_eq!("da\neb\nfc\n", grid.rotate_clockwise().to_string());
+    assert_eq!("cf\nbe\nad\n", grid.rotate_counterclockwise().to_string());
+    assert_eq!(grid, grid.rotate_clockwise().rotate_counterclockwise());
+}
+
+#[test]
+fn test_2() {
+    let grid = Grid::new(3, 3, 0);
+    assert_eq!(vec![(0, 1), (1, 0)], grid.neighbours4((0, 0)).collect::<Vec<_>>());
+    assert_eq!(8, grid.neighbours8((1, 1)).count());
+    assert_eq!(3, grid.neighbours8((2, 2)).count());
+    assert_eq!(None, grid.step((0, 2), Direction::Right));
+    assert_eq!(Some((1, 2)), grid.step((0, 2), Direction::Down));
+    assert_eq!(Direction::Left, Direction::Up.turn_left());
+}
+
+#[test]
+fn test_3() {
+    let e = Grid::parse("..#\n.x.\n", |c| "#.".contains(c).then_some(c), "'#' or '.'").unwrap_err();
+    assert_eq!((2, 2, "\"x\""), (e.line, e.column, e.found.as_str()));
+    let e = Grid::parse("..#\n..\n", Some, "a character").unwrap_err();
+    assert_eq!((2, 3, "end of line"), (e.line, e.column, e.found.as_str()));
+    let e = Grid::parse("..#\n..#.\n", Some, "a character").unwrap_err();
+    assert_eq!((2, 4, "a row of 3 cells"), (e.line, e.column, e.expected.as_str()));
+    assert!(Grid::parse("", Some, "a character").is_err());
+}
+
+// random rectangles of characters, as the rows of a grid
+#[cfg(test)]
+fn arbitrary_rows() -> impl proptest::strategy::Strategy<Value = Vec<Vec<char>>> {
+    use proptest::prelude::*;
+    let cell = prop::sample::select(vec!['.', '#', 'O', '|', '-', '7', 'S', '9']);
+    (1..20usize, 1..20usize).prop_flat_map(move |(w, h)| prop::collection::vec(prop::collection::vec(cell.clone(), w), h))
+}
+
+// a grid prints back to the text it was parsed from
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_4(rows in arbitrary_rows()) {
+        let text: String = rows.iter().map(|row| row.iter().collect::<String>() + "\n").collect();
+        let grid = Grid::parse(&text, Some, "a character").unwrap();
+        proptest::prop_assert_eq!(&text, &grid.to_string());
+        proptest::prop_assert_eq!(&grid, &Grid::from_rows(rows));
+    }
+}
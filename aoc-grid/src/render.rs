@@ -0,0 +1,224 @@
+/*
+ * Grids drawn on the terminal with ANSI colours, for `aoc run --visualize`.
+ *
+ * every cell is drawn with its glyph, then the layers are painted over it in order: a layer is
+ * a set of cells and a style, a later layer wins for the colours it sets.
+ *
+ *   Render::new(&maze.map, pipe_glyph)
+ *   .layer(path, Style::fg(Color::Yellow).bold())
+ *   .layer(inside, Style::bg(Color::Green))
+ *
+ * an Animation draws its frames in place, each one over the previous one. a Render also gives a
+ * Picture with the colours of its cells, to export it as an image.
+ */
+
+use std::fmt::{self, Display};
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+use aoc_core::picture::{Picture, Rgb};
+use crate::{Grid, Pos};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    // the SGR parameters, 30 is added for the foreground and 40 for the background
+    fn code(self, base: u8) -> String {
+        match self {
+            Color::Black => base.to_string(),
+            Color::Red => (base + 1).to_string(),
+            Color::Green => (base + 2).to_string(),
+            Color::Yellow => (base + 3).to_string(),
+            Color::Blue => (base + 4).to_string(),
+            Color::Magenta => (base + 5).to_string(),
+            Color::Cyan => (base + 6).to_string(),
+            Color::White => (base + 7).to_string(),
+            Color::Gray => format!("{};5;8", base + 8),
+            Color::Rgb(r, g, b) => format!("{};2;{};{};{}", base + 8, r, g, b),
+        }
+    }
+
+    /// the colour of a usual dark terminal theme
+    pub fn rgb(self) -> Rgb {
+        match self {
+            Color::Black => (0, 0, 0),
+            Color::Red => (205, 49, 49),
+            Color::Green => (13, 188, 121),
+            Color::Yellow => (229, 229, 16),
+            Color::Blue => (36, 114, 200),
+            Color::Magenta => (188, 63, 188),
+            Color::Cyan => (17, 168, 205),
+            Color::White => (229, 229, 229),
+            Color::Gray => (102, 102, 102),
+            Color::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+}
+
+// the terminal background, for the pictures
+const BACKGROUND: Rgb = (30, 30, 30);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+}
+
+impl Style {
+    pub fn fg(color: Color) -> Style {
+        Style { fg: Some(color), ..Style::default() }
+    }
+
+    pub fn bg(color: Color) -> Style {
+        Style { bg: Some(color), ..Style::default() }
+    }
+
+    /// the same style on a background
+    pub fn on(self, color: Color) -> Style {
+        Style { bg: Some(color), ..self }
+    }
+
+    pub fn bold(self) -> Style {
+        Style { bold: true, ..self }
+    }
+
+    // this style with `top` painted over it
+    fn under(self, top: Style) -> Style {
+        Style { fg: top.fg.or(self.fg), bg: top.bg.or(self.bg), bold: self.bold || top.bold }
+    }
+
+    // the escape sequence that switches to this style from the default one
+    fn escape(self) -> String {
+        let codes: Vec<String> = self.bold.then(|| String::from("1")).into_iter()
+        .chain(self.fg.map(|c| c.code(30)))
+        .chain(self.bg.map(|c| c.code(40)))
+        .collect();
+        format!("\x1b[{}m", codes.join(";"))
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    glyph: Box<dyn Fn(&T) -> char + 'a>,
+    styles: Grid<Style>,
+}
+
+impl<'a, T> Render<'a, T> {
+    pub fn new(grid: &'a Grid<T>, glyph: impl Fn(&T) -> char + 'a) -> Render<'a, T> {
+        let styles = Grid::new(grid.width(), grid.height(), Style::default());
+        Render { grid, glyph: Box::new(glyph), styles }
+    }
+
+    /// paint the cells with the style, over the previous layers
+    pub fn layer(mut self, cells: impl IntoIterator<Item = Pos>, style: Style) -> Render<'a, T> {
+        for pos in cells {
+            self.styles[pos] = self.styles[pos].under(style);
+        }
+        self
+    }
+
+    /// paint the cells for which `style` gives a style
+    pub fn layer_by(self, style: impl Fn(Pos, &T) -> Option<Style>) -> Render<'a, T> {
+        let grid = self.grid;
+        let cells: Vec<(Pos, Style)> = grid.iter().filter_map(|(pos, c)| style(pos, c).map(|s| (pos, s))).collect();
+        cells.into_iter().fold(self, |render, (pos, style)| render.layer([pos], style))
+    }
+
+    /// a cell is painted with its background, or with its foreground when its glyph is drawn.
+    /// the cells without a colour are left dark
+    pub fn picture(&self) -> Picture {
+        let mut picture = Picture::new(self.grid.width(), self.grid.height(), BACKGROUND);
+        for (pos, c) in self.grid.iter() {
+            let style = self.styles[pos];
+            let fg = style.fg.filter(|_| (self.glyph)(c) != ' ');
+            if let Some(color) = style.bg.or(fg) {
+                picture.set(pos, color.rgb());
+            }
+        }
+        picture
+    }
+}
+
+impl<T> Display for Render<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (row, styles) in self.grid.rows().zip(self.styles.rows()) {
+            // the escape sequences are only written when the style changes
+            let mut current = Style::default();
+            for (c, &style) in row.iter().zip(styles) {
+                if style != current {
+                    if current != Style::default() {
+                        write!(f, "{}", RESET)?;
+                    }
+                    if style != Style::default() {
+                        write!(f, "{}", style.escape())?;
+                    }
+                    current = style;
+                }
+                write!(f, "{}", (self.glyph)(c))?;
+            }
+            if current != Style::default() {
+                write!(f, "{}", RESET)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// frames drawn over each other on stdout
+pub struct Animation {
+    delay: Duration,
+    // the height of the last frame, to go back up over it
+    lines: usize,
+}
+
+impl Animation {
+    pub fn new(delay: Duration) -> Animation {
+        Animation { delay, lines: 0 }
+    }
+
+    /// draw the frame over the previous one, then wait for the delay
+    pub fn frame(&mut self, frame: &str) {
+        let mut out = io::stdout().lock();
+        // a closed stdout ends the animation, there is no one to show it to anyway
+        let _ = match self.lines {
+            0 => write!(out, "{}", frame),
+            n => write!(out, "\x1b[{}A\x1b[J{}", n, frame),
+        }
+        .and_then(|_| out.flush());
+        self.lines = frame.lines().count();
+        thread::sleep(self.delay);
+    }
+}
+
+#[test]
+fn test_1() {
+    let grid = Grid::parse("ab\ncd", Some, "a letter").unwrap();
+    let render = Render::new(&grid, |&c| c.to_ascii_uppercase());
+    assert_eq!("AB\nCD\n", render.to_string());
+
+    let render = render
+    .layer([(0, 0), (0, 1)], Style::fg(Color::Red))
+    .layer([(0, 1)], Style::bg(Color::Rgb(0, 0, 255)).bold());
+    let picture = render.picture();
+    assert_eq!(((205, 49, 49), (0, 0, 255), BACKGROUND), (picture.get((0, 0)), picture.get((0, 1)), picture.get((1, 0))));
+    assert_eq!("\x1b[31mA\x1b[0m\x1b[1;31;48;2;0;0;255mB\x1b[0m\nCD\n", render.to_string());
+
+    let render = Render::new(&grid, |&c| c).layer_by(|(i, _), _| (i == 1).then(|| Style::fg(Color::Gray)));
+    assert_eq!("ab\n\x1b[38;5;8mcd\x1b[0m\n", render.to_string());
+}
@@ -0,0 +1,195 @@
+/*
+ * Searches over any kind of state: a position, a position and a heading, a whole grid... States
+ * only need to be hashed and compared, the edges are given by a function returning the
+ * successors of a state (with the cost of the move for dijkstra and astar).
+ *
+ * dijkstra, astar and bfs stop on the first goal and keep the cost and the predecessor of every
+ * state they reached, to rebuild the path. flood_fill only tells which states can be reached.
+ */
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    goal: Option<S>,
+    // the cost of each state reached, and the state it was reached from
+    reached: HashMap<S, (usize, Option<S>)>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    /// the goal that was found, the cheapest one
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// the cost of the goal
+    pub fn cost(&self) -> Option<usize> {
+        self.goal.as_ref().and_then(|goal| self.cost_of(goal))
+    }
+
+    pub fn cost_of(&self, state: &S) -> Option<usize> {
+        self.reached.get(state).map(|&(cost, _)| cost)
+    }
+
+    /// the states from a start to `to`, both included
+    pub fn path_to(&self, to: &S) -> Option<Vec<S>> {
+        let mut path = vec![to.clone()];
+        let mut from = self.reached.get(to)?.1.clone();
+        while let Some(state) = from {
+            from = self.reached[&state].1.clone();
+            path.push(state);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// the path to the goal
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+
+    pub fn reached(&self) -> impl Iterator<Item = (&S, usize)> + '_ {
+        self.reached.iter().map(|(state, &(cost, _))| (state, cost))
+    }
+}
+
+/// cheapest path from any of the starts to a goal. the heuristic must never overestimate the
+/// cost left to a goal, nor drop by more than the cost of a move
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut reached: HashMap<S, (usize, Option<S>)> = HashMap::new();
+    let mut done = HashSet::new();
+    // the states are not ordered, the queue holds their index in `pushed`
+    let mut pushed = Vec::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if !reached.contains_key(&start) {
+            reached.insert(start.clone(), (0, None));
+            queue.push(Reverse((heuristic(&start), pushed.len())));
+            pushed.push(start);
+        }
+    }
+
+    while let Some(Reverse((_, i))) = queue.pop() {
+        let state = pushed[i].clone();
+        if !done.insert(state.clone()) {
+            continue;
+        }
+        if is_goal(&state) {
+            return Search { goal: Some(state), reached };
+        }
+        let cost = reached[&state].0;
+        for (next, step) in successors(&state) {
+            let cost = cost + step;
+            if !done.contains(&next) && reached.get(&next).is_none_or(|&(c, _)| cost < c) {
+                reached.insert(next.clone(), (cost, Some(state.clone())));
+                queue.push(Reverse((cost + heuristic(&next), pushed.len())));
+                pushed.push(next);
+            }
+        }
+    }
+    Search { goal: None, reached }
+}
+
+/// cheapest path from any of the starts to a goal
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+/// shortest path from any of the starts to a goal, when every move costs 1
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut reached: HashMap<S, (usize, Option<S>)> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !reached.contains_key(&start) {
+            reached.insert(start.clone(), (0, None));
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            return Search { goal: Some(state), reached };
+        }
+        let cost = reached[&state].0;
+        for next in successors(&state) {
+            if !reached.contains_key(&next) {
+                reached.insert(next.clone(), (cost + 1, Some(state.clone())));
+                queue.push_back(next);
+            }
+        }
+    }
+    Search { goal: None, reached }
+}
+
+/// every state that can be reached from the starts, the starts included
+pub fn flood_fill<S, I>(starts: impl IntoIterator<Item = S>, mut successors: impl FnMut(&S) -> I) -> HashSet<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut reached = HashSet::new();
+    let mut stack: Vec<S> = starts.into_iter().collect();
+    while let Some(state) = stack.pop() {
+        if reached.insert(state.clone()) {
+            stack.extend(successors(&state).into_iter().filter(|next| !reached.contains(next)));
+        }
+    }
+    reached
+}
+
+#[test]
+fn test_1() {
+    use crate::{Grid, Pos};
+
+    let maze = Grid::parse("S...\n.##.\n...E\n", Some, "").unwrap();
+    let start = maze.position(|&c| c == 'S').unwrap();
+    let end = maze.position(|&c| c == 'E').unwrap();
+    let open = |p: &Pos| maze.neighbours4(*p).filter(|&n| maze[n] != '#').collect::<Vec<_>>();
+
+    let search = bfs([start], open, |&p| p == end);
+    assert_eq!(Some(5), search.cost());
+    assert_eq!(Some(6), search.path().map(|p| p.len()));
+    assert_eq!(Some(2), search.cost_of(&(2, 0)));
+
+    // walking on the bottom row is cheap
+    let cost = |p: Pos| if p.0 == 2 { 1 } else { 10 };
+    let weighted = |p: &Pos| open(p).into_iter().map(|n| (n, cost(n))).collect::<Vec<_>>();
+    let search = dijkstra([start], weighted, |&p| p == end);
+    assert_eq!(Some(14), search.cost());
+    let manhattan = |&(i, j): &Pos| end.0.abs_diff(i) + end.1.abs_diff(j);
+    let search = astar([start], weighted, manhattan, |&p| p == end);
+    assert_eq!(Some(14), search.cost());
+    assert_eq!(Some(vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (2, 3)]), search.path());
+
+    // walls are never entered, but a start is always reached
+    assert_eq!(None, bfs([start], open, |&p| p == (1, 1)).goal());
+    assert_eq!(10, flood_fill([start], open).len());
+    assert_eq!(11, flood_fill([start, (1, 1)], open).len());
+}
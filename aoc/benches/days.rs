@@ -0,0 +1,98 @@
+/*
+ * Benchmarks of every day: parsing and both parts, on the examples of the puzzle texts, on a
+ * generated input of the size of the real ones (see aoc-gen) and on the real input when there
+ * is one ($AOC_INPUT_DIR/dayN, then dayN/assets/input).
+ *
+ *   cargo bench --bench days            every day
+ *   cargo bench --bench days -- day12   a single day
+ *
+ * some real inputs take seconds per iteration (day5 part 2 tries every seed), so each
+ * benchmark only takes the minimum of 10 samples.
+ */
+
+use std::fs::read_to_string;
+use std::hint::black_box;
+use std::path::Path;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use aoc_core::{Part, Solution, Source};
+
+const BOTH: &[Part] = &Part::ALL;
+const ONE: &[Part] = &[Part::One];
+const TWO: &[Part] = &[Part::Two];
+
+// the files of dayN/assets taken from the puzzle texts, with the parts they are an example of
+fn examples(day: u8) -> &'static [(&'static str, &'static [Part])] {
+    match day {
+        1 => &[("example", BOTH), ("example2", TWO)],
+        8 => &[("example", ONE), ("test2", ONE), ("test3", TWO)],
+        10 => &[("example", ONE), ("example2", TWO)],
+        12 => &[("debug", ONE), ("test2", BOTH)],
+        17 => &[("example", BOTH), ("test2", TWO)],
+        20 => &[("example", ONE), ("test2", ONE)],
+        _ => &[("example", BOTH)],
+    }
+}
+
+fn inputs(day: u8) -> Vec<(&'static str, String, &'static [Part])> {
+    let assets = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../day{}/assets", day));
+    let mut inputs: Vec<_> = examples(day).iter()
+        .map(|&(name, parts)| (name, read_to_string(assets.join(name)).expect("missing example"), parts))
+        .collect();
+    inputs.push(("generated", aoc_gen::generate(day, 2023, None).unwrap(), BOTH));
+    let source = Source::resolve(day, None);
+    match source.read() {
+        Ok(input) => inputs.push(("input", input, BOTH)),
+        Err(_) => eprintln!("day{}: no puzzle input at {}, only the examples are benchmarked", day, source),
+    }
+    inputs
+}
+
+fn bench<S: Solution>(c: &mut Criterion, day: u8) {
+    let mut group = c.benchmark_group(format!("day{}", day));
+    group.sample_size(10);
+    for (name, input, parts) in inputs(day) {
+        let parsed = match S::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("day{}: {}", day, e.in_file(name));
+                continue;
+            }
+        };
+        group.bench_with_input(BenchmarkId::new("parse", name), &input, |b, input| {
+            b.iter(|| S::parse(black_box(input)))
+        });
+        for &part in parts {
+            group.bench_with_input(BenchmarkId::new(format!("part{}", part), name), &parsed, |b, parsed| match part {
+                Part::One => b.iter(|| S::part_one(black_box(parsed))),
+                Part::Two => b.iter(|| S::part_two(black_box(parsed))),
+            });
+        }
+    }
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench::<day1::Day1>(c, 1);
+    bench::<day2::Day2>(c, 2);
+    bench::<day3::Day3>(c, 3);
+    bench::<day4::Day4>(c, 4);
+    bench::<day5::Day5>(c, 5);
+    bench::<day6::Day6>(c, 6);
+    bench::<day7::Day7>(c, 7);
+    bench::<day8::Day8>(c, 8);
+    bench::<day9::Day9>(c, 9);
+    bench::<day10::Day10>(c, 10);
+    bench::<day11::Day11>(c, 11);
+    bench::<day12::Day12>(c, 12);
+    bench::<day13::Day13>(c, 13);
+    bench::<day14::Day14>(c, 14);
+    bench::<day15::Day15>(c, 15);
+    bench::<day16::Day16>(c, 16);
+    bench::<day17::Day17>(c, 17);
+    bench::<day18::Day18>(c, 18);
+    bench::<day19::Day19>(c, 19);
+    bench::<day20::Day20>(c, 20);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,131 @@
+/*
+ * Every day of the calendar at once, with `aoc run --all`:
+ *
+ *   day  part 1            part 2            parse       part 1      part 2      status
+ *     6  316800            45647654          6.2µs       1.1µs       0.8µs       ok
+ *     7  ...
+ *
+ * the days run in parallel, each on its own thread: the solvers share nothing but the log.
+ * a day without an input is skipped, a day that panics, does not parse or has a part that cannot
+ * answer fails the run, as does a wrong answer when they are checked.
+ */
+
+use std::any::Any;
+use std::thread;
+use std::time::Duration;
+
+use aoc_core::{debug, InputError, Part, Run, RunError, Source};
+use crate::answers::{Answers, Verdict};
+use crate::solvers::{solver, Solver};
+
+pub enum Outcome {
+    NoInput(InputError),
+    Panicked(String),
+    Ran(Result<Run, RunError>),
+}
+
+pub struct DayRun {
+    pub day: u8,
+    pub source: Source,
+    pub outcome: Outcome,
+}
+
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map_or(String::from("unknown panic"), |m| m.to_string()),
+    }
+}
+
+/// solve the given parts of every day, in the order of the calendar
+pub fn run_all(parts: &[Part]) -> Vec<DayRun> {
+    let days: Vec<(u8, Solver)> = (1..=25).filter_map(|day| solver(day).map(|s| (day, s))).collect();
+    thread::scope(|scope| {
+        let threads: Vec<_> = days.iter().map(|&(day, solver)| {
+            let source = Source::resolve(day, None);
+            let input = source.read();
+            let thread = scope.spawn(move || input.map(|input| solver(&input, parts)));
+            (day, source, thread)
+        }).collect();
+
+        threads.into_iter().map(|(day, source, thread)| {
+            let outcome = match thread.join() {
+                Ok(Err(e)) => Outcome::NoInput(e),
+                Ok(Ok(run)) => Outcome::Ran(run.map_err(|e| e.in_file(&source))),
+                Err(payload) => Outcome::Panicked(panic_message(payload)),
+            };
+            DayRun { day, source, outcome }
+        }).collect()
+    })
+}
+
+fn time(run: &Run, part: Part) -> String {
+    run.answers.iter().find(|(p, _, _)| *p == part).map_or(String::from("-"), |(_, _, t)| format!("{:.1?}", t))
+}
+
+fn answer(run: &Run, part: Part) -> &str {
+    run.answers.iter().find(|(p, _, _)| *p == part).map_or("-", |(_, a, _)| a)
+}
+
+// the first wrong answer, or whether they were all checked
+fn status(day: u8, run: &Run, answers: Option<&Answers>) -> (String, bool) {
+    let Some(answers) = answers else {
+        return (String::new(), false);
+    };
+    let verdicts: Vec<(Part, Verdict)> = run.answers.iter().map(|(p, a, _)| (*p, answers.check(day, *p, a))).collect();
+    match verdicts.iter().find(|(_, v)| v.is_failure()) {
+        Some((part, verdict)) => (format!("part {}: {}", part, verdict), true),
+        None if verdicts.iter().all(|(_, v)| *v == Verdict::Correct) => (String::from("ok"), false),
+        None => (String::from("unverified"), false),
+    }
+}
+
+/// print the summary table, the diagnostics go to stderr. true when the run failed
+pub fn print_table(runs: &[DayRun], answers: Option<&Answers>, elapsed: Duration) -> bool {
+    let mut failed = false;
+    let mut solved = 0;
+    println!("{:>3}  {:<16}  {:<16}  {:<10}  {:<10}  {:<10}  status", "day", "part 1", "part 2", "parse", "part 1", "part 2");
+    for run in runs {
+        let status = match &run.outcome {
+            Outcome::NoInput(e) => {
+                debug!("{}", e);
+                String::from("skipped, no input")
+            }
+            Outcome::Ran(Err(e)) => {
+                eprintln!("{}", e);
+                failed = true;
+                match e {
+                    RunError::Parse(_) => String::from("FAILED, the input does not parse"),
+                    RunError::Part(part, _) => format!("FAILED, part {} cannot answer the input", part),
+                }
+            }
+            Outcome::Panicked(message) => {
+                failed = true;
+                format!("PANICKED: {}", message)
+            }
+            Outcome::Ran(Ok(r)) => {
+                let (status, wrong) = status(run.day, r, answers);
+                failed |= wrong;
+                solved += 1;
+                let line = format!(
+                    "{:>3}  {:<16}  {:<16}  {:<10}  {:<10}  {:<10}  {}",
+                    run.day, answer(r, Part::One), answer(r, Part::Two),
+                    format!("{:.1?}", r.parse_time), time(r, Part::One), time(r, Part::Two), status
+                );
+                println!("{}", line.trim_end());
+                continue;
+            }
+        };
+        println!("{:>3}  {:<16}  {:<16}  {:<10}  {:<10}  {:<10}  {}", run.day, "-", "-", "-", "-", "-", status);
+    }
+    println!("{} of {} days solved in {:.1?}", solved, runs.len(), elapsed);
+    failed
+}
+
+#[test]
+fn test_1() {
+    let payload = std::panic::catch_unwind(|| panic!("no path to {}", "rx")).unwrap_err();
+    assert_eq!("no path to rx", panic_message(payload));
+    let payload = std::panic::catch_unwind(|| panic!("forbidden char")).unwrap_err();
+    assert_eq!("forbidden char", panic_message(payload));
+}
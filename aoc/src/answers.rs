@@ -0,0 +1,125 @@
+/*
+ * Known answers, used to catch regressions when refactoring a solver.
+ *
+ *   [day6.part1]
+ *   answer = 316800
+ *
+ *   [day12.part2]
+ *   wrong = [723071774775, 720117864689]
+ *
+ * every entry is optional: a part can have only an answer, only wrong answers, or nothing at all.
+ */
+
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::fs::read_to_string;
+
+use aoc_core::Part;
+use serde::Deserialize;
+
+// answers are compared as text, but are usually written as plain integers
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Value {
+    Int(i64),
+    Str(String),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Int(i) => write!(f, "{}", i),
+            Value::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Expected {
+    answer: Option<Value>,
+    #[serde(default)]
+    wrong: Vec<Value>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers(HashMap<String, HashMap<String, Expected>>);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Mismatch(String),
+    KnownWrong,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Mismatch(_) | Verdict::KnownWrong)
+    }
+
+    /// short name of the verdict, for the json reports
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "ok",
+            Verdict::Mismatch(_) => "mismatch",
+            Verdict::KnownWrong => "known_wrong",
+            Verdict::Unknown => "unverified",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "ok"),
+            Verdict::Mismatch(expected) => write!(f, "MISMATCH, expected {}", expected),
+            Verdict::KnownWrong => write!(f, "WRONG, this answer was already rejected"),
+            Verdict::Unknown => write!(f, "unverified"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn load(path: &str) -> Result<Answers, String> {
+        let content = read_to_string(path).map_err(|e| format!("cannot read {}: {}", path, e))?;
+        Answers::parse(&content).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    pub(crate) fn parse(content: &str) -> Result<Answers, String> {
+        toml::from_str(content).map_err(|e| e.to_string())
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Verdict {
+        let Some(expected) = self.0.get(&format!("day{}", day)).and_then(|d| d.get(&format!("part{}", part))) else {
+            return Verdict::Unknown;
+        };
+
+        if expected.wrong.iter().any(|w| w.to_string() == answer) {
+            return Verdict::KnownWrong;
+        }
+
+        match &expected.answer {
+            Some(a) if a.to_string() == answer => Verdict::Correct,
+            Some(a) => Verdict::Mismatch(a.to_string()),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[test]
+fn test_1() {
+    let answers = Answers::parse("
+[day6.part1]
+answer = 316800
+
+[day12.part2]
+wrong = [723071774775, \"720117864689\"]
+").unwrap();
+
+    assert_eq!(Verdict::Correct, answers.check(6, Part::One, "316800"));
+    assert_eq!(Verdict::Mismatch(String::from("316800")), answers.check(6, Part::One, "42"));
+    assert_eq!(Verdict::Unknown, answers.check(6, Part::Two, "42"));
+    assert_eq!(Verdict::KnownWrong, answers.check(12, Part::Two, "723071774775"));
+    assert_eq!(Verdict::KnownWrong, answers.check(12, Part::Two, "720117864689"));
+    assert_eq!(Verdict::Unknown, answers.check(12, Part::Two, "42"));
+}
@@ -0,0 +1,98 @@
+/*
+ * Pictures of the puzzles written as images, with `aoc export`:
+ *
+ *   aoc export --day 18 --format svg --output /tmp
+ *
+ * writes /tmp/day18-lagoon.svg, the lagoon with its trenches in the colours of the dig plan.
+ * each cell of a picture is a square of --scale pixels. the svg draws a rectangle per run of
+ * cells of the same colour on a row, the png has a pixel per pixel.
+ */
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use clap::ValueEnum;
+use aoc_core::picture::{Picture, Rgb};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Png,
+    Svg,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Png => "png",
+            Format::Svg => "svg",
+        }
+    }
+}
+
+fn png(picture: &Picture, scale: usize, out: impl Write) -> Result<(), png::EncodingError> {
+    let (width, height) = (picture.width() * scale, picture.height() * scale);
+    let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut data = Vec::with_capacity(width * height * 3);
+    for row in picture.rows() {
+        let line: Vec<u8> = row.iter()
+        .flat_map(|&(r, g, b)| [r, g, b].repeat(scale))
+        .collect();
+        for _ in 0..scale {
+            data.extend_from_slice(&line);
+        }
+    }
+    encoder.write_header()?.write_image_data(&data)
+}
+
+fn svg(picture: &Picture, scale: usize) -> String {
+    let (width, height) = (picture.width() * scale, picture.height() * scale);
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" shape-rendering=\"crispEdges\">\n",
+        width, height
+    );
+    for (i, row) in picture.rows().enumerate() {
+        let mut j = 0;
+        while j < row.len() {
+            let color: Rgb = row[j];
+            let run = row[j..].iter().take_while(|&&c| c == color).count();
+            svg += &format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#{:02x}{:02x}{:02x}\"/>\n",
+                j * scale, i * scale, run * scale, scale, color.0, color.1, color.2
+            );
+            j += run;
+        }
+    }
+    svg + "</svg>\n"
+}
+
+pub fn write(picture: &Picture, format: Format, scale: usize, path: &Path) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    match format {
+        Format::Png => png(picture, scale, &mut out).map_err(io::Error::other)?,
+        Format::Svg => out.write_all(svg(picture, scale).as_bytes())?,
+    }
+    out.flush()
+}
+
+#[test]
+fn test_1() {
+    let mut picture = Picture::new(3, 1, (0, 0, 0));
+    picture.set((0, 2), (255, 16, 0));
+    assert_eq!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"6\" height=\"2\" shape-rendering=\"crispEdges\">\n\
+         <rect x=\"0\" y=\"0\" width=\"4\" height=\"2\" fill=\"#000000\"/>\n\
+         <rect x=\"4\" y=\"0\" width=\"2\" height=\"2\" fill=\"#ff1000\"/>\n\
+         </svg>\n",
+        svg(&picture, 2)
+    );
+
+    let mut bytes = Vec::new();
+    png(&picture, 2, &mut bytes).unwrap();
+    let mut reader = png::Decoder::new(io::Cursor::new(bytes)).read_info().unwrap();
+    let mut data = vec![0; reader.output_buffer_size().unwrap()];
+    let info = reader.next_frame(&mut data).unwrap();
+    assert_eq!((6, 2), (info.width, info.height));
+    assert_eq!([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 16, 0, 255, 16, 0], data[18..36]);
+}
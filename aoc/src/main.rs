@@ -0,0 +1,288 @@
+/*
+ * Single entry point for every day of the calendar:
+ *
+ *   aoc run --day 14 --part 2 --input day14/assets/input
+ *
+ * without --part both parts are solved. each answer is reported as "day <n> part <p>: <answer>"
+ * so that runs can be scripted. with --answers answers.toml the answers are also checked against
+ * the known ones, and the exit code is non-zero on a mismatch. an input that does not parse is
+ * reported with its file, line and column.
+ *
+ * with --json a single report with the timings is printed instead, see report.rs. the debug
+ * output of the solvers is hidden unless asked for with -v (-vv for traces), on stderr or in
+ * the file given with --log.
+ *
+ * with --all every day is solved on its own input, see all.rs. with --visualize the puzzle is
+ * drawn on the terminal instead of solved, for the days that can draw it: an animation plays
+ * with --delay milliseconds between its frames.
+ *
+ *   aoc gen --day 10 --seed 42 --size 1000 > day10.txt
+ *
+ * writes a synthetic input, the same for the same seed and size.
+ *
+ *   aoc export --day 18 --format png --output /tmp
+ *
+ * writes the pictures of a day as images, see export.rs.
+ *
+ *   aoc serve --address 127.0.0.1:8023
+ *
+ * answers POST /day/<n>/part/<p> with the input as body, when built with the `server` feature.
+ * see server.rs.
+ */
+
+mod all;
+mod answers;
+mod export;
+mod report;
+#[cfg(feature = "server")]
+mod server;
+mod solvers;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use clap::{ArgAction, Parser, Subcommand};
+use aoc_core::{log, Part, Source};
+use aoc_grid::render::Animation;
+use answers::Answers;
+use export::Format;
+use report::Report;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of code 2023 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// show the debug output of the solvers, twice for traces
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+    /// write the debug output to this file instead of stderr
+    #[arg(long, global = true)]
+    log: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one part of one day, or of every day
+    Run {
+        /// day of the calendar (1-25)
+        #[arg(long, required_unless_present = "all")]
+        day: Option<u8>,
+        /// solve every day in parallel and print a summary table
+        #[arg(long, conflicts_with_all = ["day", "input"])]
+        all: bool,
+        /// part of the puzzle (1 or 2), both when omitted
+        #[arg(long)]
+        part: Option<Part>,
+        /// puzzle input, `-` for stdin. defaults to $AOC_INPUT_DIR/dayN, then dayN/assets/input
+        #[arg(long)]
+        input: Option<String>,
+        /// known answers to check the results against
+        #[arg(long)]
+        answers: Option<String>,
+        /// print a json report with the timings
+        #[arg(long)]
+        json: bool,
+        /// draw the puzzle on the terminal instead of printing the answers
+        #[arg(long, conflicts_with_all = ["all", "json", "answers", "part"])]
+        visualize: bool,
+        /// milliseconds between the frames of an animation
+        #[arg(long, default_value_t = 50, requires = "visualize")]
+        delay: u64,
+    },
+    /// Write a synthetic input for one day
+    Gen {
+        /// day of the calendar (1-25)
+        #[arg(long)]
+        day: u8,
+        /// seed of the random generator
+        #[arg(long, default_value_t = 2023)]
+        seed: u64,
+        /// size of the input, see the generator of the day. close to the real input by default
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Write the pictures of a day as images
+    Export {
+        /// day of the calendar (1-25)
+        #[arg(long)]
+        day: u8,
+        /// puzzle input, `-` for stdin. defaults to $AOC_INPUT_DIR/dayN, then dayN/assets/input
+        #[arg(long)]
+        input: Option<String>,
+        #[arg(long, value_enum, default_value_t = Format::Png)]
+        format: Format,
+        /// pixels per cell
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+        /// directory the images are written to, as dayN-<picture>.png
+        #[arg(long, default_value = ".")]
+        output: PathBuf,
+    },
+    /// Answer POST /day/<n>/part/<p> with the input as body
+    #[cfg(feature = "server")]
+    Serve {
+        /// address to listen on
+        #[arg(long, default_value = "127.0.0.1:8023")]
+        address: String,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    log::set_verbosity(cli.verbose);
+    if let Some(path) = &cli.log {
+        if let Err(e) = log::set_file(path) {
+            eprintln!("cannot write the log to {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    match cli.command {
+        Command::Run { day, all, part, input, answers, json, visualize, delay } => {
+            let answers = match answers.as_deref().map(Answers::load) {
+                Some(Ok(answers)) => Some(answers),
+                Some(Err(e)) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+                None => None,
+            };
+            let parts = part.map_or(Part::ALL.to_vec(), |p| vec![p]);
+
+            if all {
+                let start = Instant::now();
+                let runs = all::run_all(&parts);
+                let failed = if json {
+                    // one report per line, the days without an input are left out
+                    let mut failed = false;
+                    for run in &runs {
+                        let report = match &run.outcome {
+                            all::Outcome::NoInput(_) => continue,
+                            all::Outcome::Panicked(message) => {
+                                Report::error(run.day, run.source.to_string(), format!("panicked: {}", message))
+                            }
+                            all::Outcome::Ran(r) => Report::new(run.day, run.source.to_string(), r, answers.as_ref()),
+                        };
+                        failed |= report.failed;
+                        println!("{}", serde_json::to_string(&report).unwrap());
+                    }
+                    failed
+                } else {
+                    all::print_table(&runs, answers.as_ref(), start.elapsed())
+                };
+                return if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS };
+            }
+
+            let day = day.unwrap();
+            let Some(solver) = solvers::solver(day) else {
+                eprintln!("no solver for day {}", day);
+                return ExitCode::FAILURE;
+            };
+            let source = Source::resolve(day, input.as_deref());
+            let input = match source.read() {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            if visualize {
+                let mut animation = Animation::new(Duration::from_millis(delay));
+                let mut frames = 0;
+                let drawn = (solvers::day(day).unwrap().visualize)(&input, &mut |frame| {
+                    animation.frame(frame);
+                    frames += 1;
+                });
+                if let Err(e) = drawn {
+                    eprintln!("{}", e.in_file(&source));
+                    return ExitCode::FAILURE;
+                }
+                if frames == 0 {
+                    eprintln!("day {} has nothing to draw", day);
+                }
+                return ExitCode::SUCCESS;
+            }
+
+            let run = solver(&input, &parts).map_err(|e| e.in_file(&source));
+
+            if json {
+                let report = Report::new(day, source.to_string(), &run, answers.as_ref());
+                println!("{}", serde_json::to_string(&report).unwrap());
+                return if report.failed { ExitCode::FAILURE } else { ExitCode::SUCCESS };
+            }
+
+            let run = match run {
+                Ok(run) => run,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let mut failed = false;
+            for (part, answer, _) in run.answers {
+                match &answers {
+                    Some(answers) => {
+                        let verdict = answers.check(day, part, &answer);
+                        failed |= verdict.is_failure();
+                        println!("day {} part {}: {} ({})", day, part, answer, verdict);
+                    }
+                    None => println!("day {} part {}: {}", day, part, answer),
+                }
+            }
+            if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+        }
+        Command::Gen { day, seed, size } => {
+            let Some(input) = aoc_gen::generate(day, seed, size) else {
+                eprintln!("no generator for day {}", day);
+                return ExitCode::FAILURE;
+            };
+            print!("{}", input);
+            ExitCode::SUCCESS
+        }
+        Command::Export { day, input, format, scale, output } => {
+            let Some(entry_points) = solvers::day(day) else {
+                eprintln!("no solver for day {}", day);
+                return ExitCode::FAILURE;
+            };
+            let source = Source::resolve(day, input.as_deref());
+            let pictures = match source.read().map(|input| (entry_points.paint)(&input)) {
+                Ok(Ok(pictures)) => pictures,
+                Ok(Err(e)) => {
+                    eprintln!("{}", e.in_file(&source));
+                    return ExitCode::FAILURE;
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            if pictures.is_empty() {
+                eprintln!("day {} has no picture to export", day);
+            }
+            for (name, picture) in pictures {
+                let path = output.join(format!("day{}-{}.{}", day, name, format.extension()));
+                if let Err(e) = export::write(&picture, format, scale, &path) {
+                    eprintln!("cannot write {}: {}", path.display(), e);
+                    return ExitCode::FAILURE;
+                }
+                println!("{}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        #[cfg(feature = "server")]
+        Command::Serve { address } => {
+            let server = match tiny_http::Server::http(&address) {
+                Ok(server) => server,
+                Err(e) => {
+                    eprintln!("cannot listen on {}: {}", address, e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            eprintln!("serving on http://{}", address);
+            server::serve(&server);
+            ExitCode::SUCCESS
+        }
+    }
+}
@@ -0,0 +1,87 @@
+/*
+ * Machine readable report of a run, printed with --json:
+ *
+ *   {"day":7,"input":"day7/assets/input","parse_time_ns":81234,"parts":[
+ *     {"part":1,"answer":"6440","solve_time_ns":9120,"verdict":"ok"},
+ *     {"part":2,"answer":"5905","solve_time_ns":9876,"verdict":"mismatch","expected":"5906"}]}
+ *
+ * the verdicts are only there when answers are checked. an input that does not parse, or that a
+ * part cannot answer, has no parts but an "error" with the diagnostic instead, as does a day that
+ * panicked.
+ */
+
+use serde::Serialize;
+use aoc_core::{Run, RunError};
+use crate::answers::{Answers, Verdict};
+
+#[derive(Debug, Serialize)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: String,
+    pub solve_time_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub input: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_time_ns: Option<u64>,
+    pub parts: Vec<PartReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    // the run gave no answers or an answer is wrong
+    #[serde(skip)]
+    pub failed: bool,
+}
+
+impl Report {
+    pub fn new(day: u8, input: String, run: &Result<Run, RunError>, answers: Option<&Answers>) -> Report {
+        let run = match run {
+            Ok(run) => run,
+            Err(e) => return Report::error(day, input, e.to_string()),
+        };
+        let mut failed = false;
+        let parts = run.answers.iter().map(|(part, answer, time)| {
+            let verdict = answers.map(|a| a.check(day, *part, answer));
+            failed |= verdict.as_ref().is_some_and(Verdict::is_failure);
+            PartReport {
+                part: *part as u8,
+                answer: answer.clone(),
+                solve_time_ns: time.as_nanos() as u64,
+                verdict: verdict.as_ref().map(Verdict::name),
+                expected: match verdict {
+                    Some(Verdict::Mismatch(expected)) => Some(expected),
+                    _ => None,
+                },
+            }
+        }).collect();
+        Report { day, input, parse_time_ns: Some(run.parse_time.as_nanos() as u64), parts, error: None, failed }
+    }
+
+    pub fn error(day: u8, input: String, error: String) -> Report {
+        Report { day, input, parse_time_ns: None, parts: Vec::new(), error: Some(error), failed: true }
+    }
+}
+
+#[test]
+fn test_1() {
+    use std::time::Duration;
+    use aoc_core::Part;
+
+    let answers = Answers::parse("[day6.part1]\nanswer = 288\n[day6.part2]\nanswer = 71503\n").unwrap();
+    let run = Ok(Run {
+        parse_time: Duration::from_nanos(1200),
+        answers: vec![(Part::One, String::from("288"), Duration::from_nanos(30)), (Part::Two, String::from("42"), Duration::from_nanos(40))],
+    });
+    let report = Report::new(6, String::from("example"), &run, Some(&answers));
+    assert!(report.failed);
+    assert_eq!(
+        r#"{"day":6,"input":"example","parse_time_ns":1200,"parts":[{"part":1,"answer":"288","solve_time_ns":30,"verdict":"ok"},{"part":2,"answer":"42","solve_time_ns":40,"verdict":"mismatch","expected":"71503"}]}"#,
+        serde_json::to_string(&report).unwrap()
+    );
+}
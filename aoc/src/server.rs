@@ -0,0 +1,117 @@
+/*
+ * The solvers over http, for the tools that do not link rust code. built with the `server`
+ * feature:
+ *
+ *   aoc serve --address 127.0.0.1:8023
+ *   curl --data-binary @day14/assets/input localhost:8023/day/14/part/2
+ *
+ * the body of the request is the puzzle input, the answer is the same json report as with
+ * `aoc run --json`:
+ *
+ *   {"day":14,"input":"request","parse_time_ns":183075,"parts":[{"part":2,"answer":"95884","solve_time_ns":41729583}]}
+ *
+ * an input that does not parse, or that the part cannot answer, gets a 422 with the diagnostic
+ * in "error", a solver that panics a 500. the requests are served by one thread per core.
+ */
+
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use serde_json::json;
+use tiny_http::{Header, Method, Request, Response, Server};
+use aoc_core::{debug, Part};
+use crate::all::panic_message;
+use crate::report::Report;
+use crate::solvers::solver;
+
+fn error(status: u16, message: &str) -> (u16, String) {
+    (status, json!({ "error": message }).to_string())
+}
+
+// "/day/14/part/2"
+fn route(url: &str) -> Option<(u8, Part)> {
+    let (day, part) = url.strip_prefix("/day/")?.split_once("/part/")?;
+    Some((day.parse().ok()?, part.parse().ok()?))
+}
+
+// the status and the json body of the response
+fn respond(method: &Method, url: &str, body: &[u8]) -> (u16, String) {
+    let Some((day, part)) = route(url) else {
+        return error(404, "expected /day/<n>/part/<1 or 2>");
+    };
+    let Some(solver) = solver(day) else {
+        return error(404, &format!("no solver for day {}", day));
+    };
+    if *method != Method::Post {
+        return error(405, "expected a POST with the puzzle input");
+    }
+    let Ok(input) = std::str::from_utf8(body) else {
+        return error(400, "the puzzle input is not utf-8");
+    };
+
+    let input_name = String::from("request");
+    let (status, report) = match panic::catch_unwind(AssertUnwindSafe(|| solver(input, &[part]))) {
+        Ok(run) => (if run.is_ok() { 200 } else { 422 }, Report::new(day, input_name, &run, None)),
+        Err(payload) => (500, Report::error(day, input_name, format!("panicked: {}", panic_message(payload)))),
+    };
+    (status, serde_json::to_string(&report).unwrap())
+}
+
+fn serve_request(mut request: Request) {
+    let mut body = Vec::new();
+    let (status, json) = match request.as_reader().read_to_end(&mut body) {
+        Ok(_) => respond(request.method(), request.url(), &body),
+        Err(e) => error(400, &e.to_string()),
+    };
+    debug!("{} {}: {}", request.method(), request.url(), status);
+
+    let header = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(json).with_status_code(status).with_header(header);
+    if let Err(e) = request.respond(response) {
+        debug!("the response was not sent: {}", e);
+    }
+}
+
+/// answer the requests until the process is stopped
+pub fn serve(server: &Server) {
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| server.incoming_requests().for_each(serve_request));
+        }
+    });
+}
+
+#[test]
+fn test_1() {
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let address = server.server_addr().to_ip().unwrap();
+    thread::spawn(move || serve(&server));
+
+    let send = |method: &str, path: &str, body: &str| {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(stream, "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", method, path, body.len(), body).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response[9..12].to_string();
+        let body = response.split_once("\r\n\r\n").unwrap().1.to_string();
+        (status, body)
+    };
+
+    let example = include_str!("../../day6/assets/example");
+    let (status, body) = send("POST", "/day/6/part/2", example);
+    assert_eq!("200", status);
+    assert!(body.contains(r#""parts":[{"part":2,"answer":"71503","#), "{}", body);
+    assert_eq!("405", send("GET", "/day/6/part/2", "").0);
+    assert_eq!("404", send("POST", "/day/6/part/3", example).0);
+    assert_eq!("404", send("POST", "/day/25/part/1", example).0);
+    let (status, body) = send("POST", "/day/6/part/1", "Time: 7\n");
+    assert_eq!("422", status);
+    assert!(body.contains(r#""error":"#), "{}", body);
+    // part one cannot answer an input only part two can
+    let (status, body) = send("POST", "/day/1/part/1", include_str!("../../day1/assets/example2"));
+    assert_eq!("422", status);
+    assert!(body.contains("expected a digit"), "{}", body);
+}
@@ -0,0 +1,133 @@
+// every day implements aoc_core::Solution, the runner goes through its entry points: a solver
+// takes the content of the puzzle input and answers the given parts, a visualizer sends the
+// frames of its drawing and a painter gives its pictures
+
+use aoc_core::{pictures, run, visualize, ParseError, Part, Picture, Run, RunError, Solution};
+
+pub type Solver = fn(&str, &[Part]) -> Result<Run, RunError>;
+pub type Visualizer = fn(&str, &mut dyn FnMut(&str)) -> Result<(), ParseError>;
+pub type Painter = fn(&str) -> Result<Vec<(&'static str, Picture)>, ParseError>;
+
+#[derive(Clone, Copy)]
+pub struct EntryPoints {
+    pub solve: Solver,
+    pub visualize: Visualizer,
+    pub paint: Painter,
+}
+
+fn entry_points<S: Solution>() -> EntryPoints {
+    EntryPoints { solve: run::<S>, visualize: visualize::<S>, paint: pictures::<S> }
+}
+
+pub fn day(day: u8) -> Option<EntryPoints> {
+    let entry_points = match day {
+        1 => entry_points::<day1::Day1>(),
+        2 => entry_points::<day2::Day2>(),
+        3 => entry_points::<day3::Day3>(),
+        4 => entry_points::<day4::Day4>(),
+        5 => entry_points::<day5::Day5>(),
+        6 => entry_points::<day6::Day6>(),
+        7 => entry_points::<day7::Day7>(),
+        8 => entry_points::<day8::Day8>(),
+        9 => entry_points::<day9::Day9>(),
+        10 => entry_points::<day10::Day10>(),
+        11 => entry_points::<day11::Day11>(),
+        12 => entry_points::<day12::Day12>(),
+        13 => entry_points::<day13::Day13>(),
+        14 => entry_points::<day14::Day14>(),
+        15 => entry_points::<day15::Day15>(),
+        16 => entry_points::<day16::Day16>(),
+        17 => entry_points::<day17::Day17>(),
+        18 => entry_points::<day18::Day18>(),
+        19 => entry_points::<day19::Day19>(),
+        20 => entry_points::<day20::Day20>(),
+        _ => return None,
+    };
+    Some(entry_points)
+}
+
+pub fn solver(day: u8) -> Option<Solver> {
+    self::day(day).map(|d| d.solve)
+}
+
+#[test]
+fn test_1() {
+    for day in 1..=20 {
+        for seed in 0..3 {
+            let input = aoc_gen::generate(day, seed, Some(20)).unwrap();
+            let run = solver(day).unwrap()(&input, &Part::ALL).unwrap_or_else(|e| panic!("day{} seed {}: {}", day, seed, e));
+            assert_eq!(2, run.answers.len());
+        }
+    }
+
+    // the grid days draw at least a frame, some of them pictures too
+    for day in [10, 11, 14, 16, 17, 18] {
+        let input = aoc_gen::generate(day, 0, Some(20)).unwrap();
+        let mut frames = 0;
+        (self::day(day).unwrap().visualize)(&input, &mut |_| frames += 1).unwrap();
+        assert!(frames > 0, "day{} draws nothing", day);
+    }
+    for day in [10, 14, 16, 18] {
+        let input = aoc_gen::generate(day, 0, Some(20)).unwrap();
+        assert!(!(self::day(day).unwrap().paint)(&input).unwrap().is_empty(), "day{} paints nothing", day);
+    }
+}
+
+// a broken input is an error, never a panic. the inputs are generated ones with a few cells
+// deleted, inserted or replaced, or anything at all, and both parts are run on those that parse
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use aoc_core::Part;
+    use super::solver;
+
+    #[derive(Debug, Clone)]
+    enum Edit {
+        Delete(usize, usize),
+        Insert(usize, char),
+        Replace(usize, char),
+    }
+
+    fn edit() -> impl Strategy<Value = Edit> {
+        // the characters the parsers look for, and a few they do not expect
+        let c = prop::sample::select("0123456789 -+,:;=|#.()[]{}<>%&ABLRSXZaeoxyz\n\r\té€".chars().collect::<Vec<_>>());
+        prop_oneof![
+            (any::<usize>(), 1..20usize).prop_map(|(i, n)| Edit::Delete(i, n)),
+            (any::<usize>(), c.clone()).prop_map(|(i, c)| Edit::Insert(i, c)),
+            (any::<usize>(), c).prop_map(|(i, c)| Edit::Replace(i, c)),
+        ]
+    }
+
+    fn apply(input: &str, edits: &[Edit]) -> String {
+        let mut chars: Vec<char> = input.chars().collect();
+        for edit in edits {
+            let at = |i: usize, len: usize| if len == 0 { 0 } else { i % len };
+            match *edit {
+                Edit::Delete(i, n) if !chars.is_empty() => {
+                    let i = at(i, chars.len());
+                    chars.drain(i..(i + n).min(chars.len()));
+                }
+                Edit::Insert(i, c) => chars.insert(at(i, chars.len() + 1), c),
+                Edit::Replace(i, c) if !chars.is_empty() => {
+                    let i = at(i, chars.len());
+                    chars[i] = c;
+                }
+                _ => {}
+            }
+        }
+        chars.into_iter().collect()
+    }
+
+    proptest! {
+        #[test]
+        fn test_2(d in 1..=20u8, seed in 0..1000u64, edits in prop::collection::vec(edit(), 1..4)) {
+            let input = apply(&aoc_gen::generate(d, seed, Some(10)).unwrap(), &edits);
+            let _ = solver(d).unwrap()(&input, &Part::ALL);
+        }
+
+        #[test]
+        fn test_3(d in 1..=20u8, input in "\\PC*") {
+            let _ = solver(d).unwrap()(&input, &Part::ALL);
+        }
+    }
+}
@@ -1,6 +1,4 @@
 #!/bin/bash
 
-for dir in */; do
-    binary_name=${dir%/}
-    cargo build --release --bin "$binary_name"
-done
\ No newline at end of file
+# the aoc runner and every dayN binary, the libraries they link come with them
+cargo build --release --workspace
@@ -0,0 +1,160 @@
+/*
+ * Calibration documents of any size, summed without holding them in memory.
+ *
+ * total reads one line at a time from a BufRead, in a buffer that is reused for every line.
+ * par_total reads chunks of about `chunk` bytes, cut at the end of a line, and sums the lines of
+ * each chunk on every core. both take the calibration value of a part:
+ *
+ *   let document = BufReader::new(File::open("day1/assets/input")?);
+ *   let total = calibration::total(document, second::calibration_value)?;
+ *
+ * totals sums both parts in a single read, for a document that can only be read once like stdin.
+ *
+ * a line without a digit is an error at that line, the total is a u64 so it does not overflow.
+ */
+
+use std::fmt::{self, Display};
+use std::io::{self, BufRead, Read};
+use rayon::prelude::*;
+use aoc_core::ParseError;
+use crate::{first, second};
+
+#[derive(Debug)]
+pub enum CalibrationError {
+    Read(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for CalibrationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CalibrationError::Read(e) => write!(f, "{}", e),
+            CalibrationError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for CalibrationError {}
+
+impl From<io::Error> for CalibrationError {
+    fn from(e: io::Error) -> Self {
+        CalibrationError::Read(e)
+    }
+}
+
+// the error of a line without a digit, `number` counts from 1
+fn no_digit(number: usize, line: &str) -> ParseError {
+    let mut error = ParseError::at(line, line, "a digit");
+    error.line = number;
+    error
+}
+
+// every line of the document with its number, without its end of line
+fn for_each_line(
+    mut document: impl BufRead,
+    mut f: impl FnMut(usize, &str) -> Result<(), CalibrationError>,
+) -> Result<(), CalibrationError> {
+    let mut line = String::new();
+    let mut number = 0;
+    loop {
+        line.clear();
+        if document.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        number += 1;
+        f(number, line.trim_end_matches('\n').trim_end_matches('\r'))?;
+    }
+}
+
+/// the sum of the calibration values of the lines, read one at a time
+pub fn total(document: impl BufRead, value: impl Fn(&str) -> Option<u64>) -> Result<u64, CalibrationError> {
+    let mut total = 0;
+    for_each_line(document, |number, line| {
+        total += value(line).ok_or_else(|| CalibrationError::Parse(no_digit(number, line)))?;
+        Ok(())
+    })?;
+    Ok(total)
+}
+
+/// the totals of both parts. a line with only spelled digits has no value in part one, which
+/// has an error instead of a total
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Totals {
+    pub first: Result<u64, ParseError>,
+    pub second: u64,
+}
+
+/// the totals of both parts from a single read of the document. a line without any digit, spelled
+/// or not, is an error for both
+pub fn totals(document: impl BufRead) -> Result<Totals, CalibrationError> {
+    let mut totals = Totals { first: Ok(0), second: 0 };
+    for_each_line(document, |number, line| {
+        totals.second += second::calibration_value(line).ok_or_else(|| CalibrationError::Parse(no_digit(number, line)))?;
+        if let Ok(total) = totals.first {
+            totals.first = first::calibration_value(line).map(|value| total + value).ok_or_else(|| no_digit(number, line));
+        }
+        Ok(())
+    })?;
+    Ok(totals)
+}
+
+/// the same sum, the lines of each chunk of about `chunk` bytes shared between the cores. a chunk
+/// is at least a line, whatever its size
+pub fn par_total(
+    mut document: impl BufRead,
+    value: impl Fn(&str) -> Option<u64> + Sync,
+    chunk: usize,
+) -> Result<u64, CalibrationError> {
+    // a chunk of 0 bytes would read nothing, the buffer grows past a megabyte if it must
+    let chunk = chunk.max(1);
+    let mut buffer = Vec::with_capacity(chunk.min(1 << 20));
+    let (mut total, mut lines) = (0, 0);
+    loop {
+        buffer.clear();
+        document.by_ref().take(chunk as u64).read_to_end(&mut buffer)?;
+        if buffer.is_empty() {
+            return Ok(total);
+        }
+        if buffer.last() != Some(&b'\n') {
+            document.read_until(b'\n', &mut buffer)?;
+        }
+        let text = std::str::from_utf8(&buffer).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        match text.par_lines().map(&value).sum::<Option<u64>>() {
+            Some(sum) => total += sum,
+            // the first line without a digit is looked for again, to locate it
+            None => {
+                let (i, line) = text.lines().enumerate().find(|&(_, line)| value(line).is_none()).unwrap();
+                return Err(CalibrationError::Parse(no_digit(lines + i + 1, line)));
+            }
+        }
+        // every chunk but the last ends with a newline
+        lines += buffer.iter().filter(|&&b| b == b'\n').count();
+    }
+}
+
+#[test]
+fn test_1() {
+    let example = include_str!("../assets/example");
+    let example2 = include_str!("../assets/example2");
+    assert_eq!(142, total(example.as_bytes(), first::calibration_value).unwrap());
+    assert_eq!(281, total(example2.as_bytes(), second::calibration_value).unwrap());
+    for chunk in [0, 1, 7, 4096, usize::MAX] {
+        assert_eq!(142, par_total(example.as_bytes(), first::calibration_value, chunk).unwrap());
+        assert_eq!(281, par_total(example2.as_bytes(), second::calibration_value, chunk).unwrap());
+    }
+
+    let document = "a1\r\n\r\nb2\n";
+    for result in [total(document.as_bytes(), first::calibration_value), par_total(document.as_bytes(), first::calibration_value, 1)] {
+        match result {
+            Err(CalibrationError::Parse(e)) => assert_eq!((2, 1, "a digit"), (e.line, e.column, e.expected.as_str())),
+            other => panic!("{:?}", other),
+        }
+    }
+    assert!(matches!(total(&b"1\n\xff\n"[..], first::calibration_value), Err(CalibrationError::Read(_))));
+
+    assert_eq!(Totals { first: Ok(142), second: 142 }, totals(example.as_bytes()).unwrap());
+    let example2 = totals(example2.as_bytes()).unwrap();
+    assert_eq!((281, 2), (example2.second, example2.first.unwrap_err().line));
+    assert!(matches!(totals("one\n\nb2\n".as_bytes()), Err(CalibrationError::Parse(e)) if e.line == 2));
+}
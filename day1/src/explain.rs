@@ -0,0 +1,172 @@
+/*
+ * Why the lines of a calibration document have the values they have: every token found in a
+ * line with its byte offsets, the first and the last of them, and the value they make.
+ *
+ *   day1 explain 2 day1/assets/example2
+ *
+ *   line 2: eightwothree = 83
+ *     0..5   "eight"  8  first
+ *     4..7   "two"    2
+ *     7..12  "three"  3  last
+ *
+ * on a terminal the line is drawn with its tokens in cyan, the first one on green and the last
+ * one on blue.
+ */
+
+use std::fmt::{self, Display};
+use std::io::{self, BufRead, Write};
+use std::ops::Range;
+use aoc_grid::render::{Color, Render, Style};
+use aoc_grid::Grid;
+use crate::vocabulary::Vocabulary;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    /// the bytes of the line it is spelled with
+    pub span: Range<usize>,
+    pub number: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    pub line: &'a str,
+    pub tokens: Vec<Token>,
+    /// None when the line has no token
+    pub value: Option<u64>,
+}
+
+impl<'a> Explanation<'a> {
+    pub fn new(vocabulary: &Vocabulary, line: &'a str) -> Explanation<'a> {
+        let tokens = vocabulary.tokens(line)
+        .map(|m| Token { span: m.range(), number: vocabulary.number(m) })
+        .collect();
+        Explanation { line, tokens, value: vocabulary.calibration_value(line) }
+    }
+
+    /// the token that starts first
+    pub fn first(&self) -> Option<&Token> {
+        self.tokens.first()
+    }
+
+    /// the token that starts last
+    pub fn last(&self) -> Option<&Token> {
+        self.tokens.last()
+    }
+
+    // the line drawn with its tokens in colour
+    fn highlight(&self) -> String {
+        let grid = Grid::from_rows(vec![self.line.chars().collect()]);
+        // the spans are in bytes, the cells in characters
+        let cells = |token: &Token| {
+            let start = self.line[..token.span.start].chars().count();
+            let end = start + self.line[token.span.clone()].chars().count();
+            (start..end).map(|j| (0, j))
+        };
+        let mut render = Render::new(&grid, |&c| c);
+        for token in &self.tokens {
+            render = render.layer(cells(token), Style::fg(Color::Cyan));
+        }
+        if let Some((first, last)) = self.first().zip(self.last()) {
+            render = render
+            .layer(cells(first), Style::bg(Color::Green).bold())
+            .layer(cells(last), Style::bg(Color::Blue).bold());
+        }
+        let line = render.to_string();
+        line.trim_end_matches('\n').to_string()
+    }
+
+    // the explanation under the line, drawn as it is given
+    fn describe(&self, f: &mut impl fmt::Write, line: &str) -> fmt::Result {
+        match self.value {
+            Some(value) => writeln!(f, "{} = {}", line, value)?,
+            None => writeln!(f, "{} = no digit", line)?,
+        }
+        let spans: Vec<String> = self.tokens.iter().map(|t| format!("{}..{}", t.span.start, t.span.end)).collect();
+        let texts: Vec<String> = self.tokens.iter().map(|t| format!("{:?}", &self.line[t.span.clone()])).collect();
+        let span_width = spans.iter().map(String::len).max().unwrap_or(0);
+        let text_width = texts.iter().map(|t| t.chars().count()).max().unwrap_or(0);
+        for (i, token) in self.tokens.iter().enumerate() {
+            let role = match (i == 0, i == self.tokens.len() - 1) {
+                (true, true) => "  first and last",
+                (true, false) => "  first",
+                (false, true) => "  last",
+                (false, false) => "",
+            };
+            let row = format!("  {:<span_width$}  {:<text_width$}  {}{}", spans[i], texts[i], token.number, role);
+            writeln!(f, "{}", row.trim_end())?;
+        }
+        Ok(())
+    }
+
+    /// the explanation with the tokens of the line in colour, for a terminal
+    pub fn highlighted(&self) -> String {
+        let mut text = String::new();
+        self.describe(&mut text, &self.highlight()).unwrap();
+        text
+    }
+}
+
+impl Display for Explanation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.describe(f, self.line)
+    }
+}
+
+/// the explanation of every line of the document, then the total of the lines that have a value.
+/// a line without a digit is explained too, it is not an error
+pub fn report(
+    vocabulary: &Vocabulary,
+    mut document: impl BufRead,
+    colour: bool,
+    mut out: impl Write,
+) -> io::Result<u64> {
+    let mut line = String::new();
+    let (mut total, mut number) = (0, 0);
+    loop {
+        line.clear();
+        if document.read_line(&mut line)? == 0 {
+            break;
+        }
+        number += 1;
+        let explanation = Explanation::new(vocabulary, line.trim_end_matches('\n').trim_end_matches('\r'));
+        if colour {
+            write!(out, "line {}: {}", number, explanation.highlighted())?;
+        } else {
+            write!(out, "line {}: {}", number, explanation)?;
+        }
+        total += explanation.value.unwrap_or(0);
+    }
+    writeln!(out, "total {}", total)?;
+    Ok(total)
+}
+
+#[test]
+fn test_1() {
+    let english = Vocabulary::english();
+    let explanation = Explanation::new(&english, "eightwothree");
+    assert_eq!(Some(83), explanation.value);
+    assert_eq!(Some(&Token { span: 4..7, number: 2 }), explanation.tokens.get(1));
+    assert_eq!(
+        "eightwothree = 83\n  \
+           0..5   \"eight\"  8  first\n  \
+           4..7   \"two\"    2\n  \
+           7..12  \"three\"  3  last\n",
+        explanation.to_string()
+    );
+    assert_eq!("xyz = no digit\n", Explanation::new(&english, "xyz").to_string());
+    let digits = Vocabulary::digits();
+    assert_eq!(
+        "a\x1b[1;36;42m1\x1b[0m\x1b[1;36;44m7\x1b[0mb = 17\n  1..2  \"1\"  1  first\n  2..3  \"7\"  7  last\n",
+        Explanation::new(&digits, "a17b").highlighted()
+    );
+    // the offsets are in bytes, the highlighted characters are found from them
+    assert_eq!("é\x1b[1;36;44m1\x1b[0m = 11\n  2..3  \"1\"  1  first and last\n", Explanation::new(&digits, "é1").highlighted());
+
+    let mut out = Vec::new();
+    let example2 = include_str!("../assets/example2");
+    assert_eq!(281, report(&english, example2.as_bytes(), false, &mut out).unwrap());
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.starts_with("line 1: two1nine = 29\n  0..3  \"two\"   2  first\n"), "{}", out);
+    assert!(out.contains("line 7: 7pqrstsixteen = 76\n"), "{}", out);
+    assert!(out.ends_with("total 281\n"), "{}", out);
+}
@@ -23,19 +23,11 @@ In this example, the calibration values of these four lines are 12, 38, 15, and
 Consider your entire calibration document. What is the sum of all of the calibration values?
 */
 
-use std::fs::read_to_string;
-use apply::Apply;
-
-pub fn puzzle(input: &str) -> u32 {
-    read_to_string(input).unwrap().lines().map(|l| {
-        l.chars()
-        .filter(|c| c.is_digit(10))
-        .collect::<Vec<char>>()
-        .apply(|d|
-            (d.first().unwrap().clone(), d.last().unwrap().clone())
-        )
-    })
-    .fold::<u32, _>(0, |acc, (f,l)| {
-        acc + format!("{}{}", f, l).parse::<u32>().unwrap()
-    })
+/// the first and the last digits of the line as a number, None when it has no digit
+pub fn calibration_value(line: &str) -> Option<u64> {
+    let mut digits = line.bytes()
+    .filter(u8::is_ascii_digit)
+    .map(|d| (d - b'0') as u64);
+    let first = digits.next()?;
+    Some(first * 10 + digits.next_back().unwrap_or(first))
 }
@@ -0,0 +1,89 @@
+/*
+--- Day 1: Trebuchet?! ---
+Something is wrong with global snow production, and you've been selected to take a look. The Elves have even given you a map; on it, they've used stars to mark the top fifty locations that are likely to be having problems.
+
+You've been doing this long enough to know that to restore snow operations, you need to check all fifty stars by December 25th.
+
+Collect stars by solving puzzles. Two puzzles will be made available on each day in the Advent calendar; the second puzzle is unlocked when you complete the first. Each puzzle grants one star. Good luck!
+
+You try to ask why they can't just use a weather machine ("not powerful enough") and where they're even sending you ("the sky") and why your map looks mostly blank ("you sure ask a lot of questions") and hang on did you just say the sky ("of course, where do you think snow comes from") when you realize that the Elves are already loading you into a trebuchet ("please hold still, we need to strap you in").
+
+As they're making the final adjustments, they discover that their calibration document (your puzzle input) has been amended by a very young Elf who was apparently just excited to show off her art skills. Consequently, the Elves are having trouble reading the values on the document.
+
+The newly-improved calibration document consists of lines of text; each line originally contained a specific calibration value that the Elves now need to recover. On each line, the calibration value can be found by combining the first digit and the last digit (in that order) to form a single two-digit number.
+
+For example:
+
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+In this example, the calibration values of these four lines are 12, 38, 15, and 77. Adding these together produces 142.
+
+Consider your entire calibration document. What is the sum of all of the calibration values?
+*/
+
+use aoc_core::{ParseError, Solution};
+use calibration::{CalibrationError, Totals};
+
+pub mod calibration;
+pub mod explain;
+pub mod first;
+pub mod second;
+pub mod vocabulary;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Totals;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    // both parts are summed while reading the document once. every line has a digit, spelled or
+    // not. part one only counts the others, a document with only spelled digits on a line is an
+    // example of part two, and an error of part one
+    fn parse(input: &str) -> Result<Totals, ParseError> {
+        match calibration::totals(input.as_bytes()) {
+            Ok(totals) => Ok(totals),
+            Err(CalibrationError::Parse(e)) => Err(e),
+            // a document in memory is always read
+            Err(CalibrationError::Read(e)) => unreachable!("{}", e),
+        }
+    }
+
+    fn part_one(totals: &Totals) -> u64 {
+        Day1::try_part_one(totals).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    fn part_two(totals: &Totals) -> u64 {
+        totals.second
+    }
+
+    fn try_part_one(totals: &Totals) -> Result<u64, ParseError> {
+        totals.first.clone()
+    }
+}
+
+#[test]
+fn test_1() {
+    let test_str = include_str!("../assets/example");
+    assert_eq!(142, Day1::part_one(&Day1::parse(test_str).unwrap()));
+}
+
+#[test]
+fn test_2() {
+    let test_str = include_str!("../assets/example2");
+    assert_eq!(281, Day1::part_two(&Day1::parse(test_str).unwrap()));
+}
+
+#[test]
+fn test_3() {
+    // a line with only spelled digits has no value in part one
+    let input = Day1::parse(include_str!("../assets/example2")).unwrap();
+    let e = Day1::try_part_one(&input).unwrap_err();
+    assert_eq!((2, 1, "a digit"), (e.line, e.column, e.expected.as_str()));
+    assert_eq!(Ok(281), Day1::try_part_two(&input));
+    let e = aoc_core::run::<Day1>(include_str!("../assets/example2"), &[aoc_core::Part::Two, aoc_core::Part::One]).unwrap_err();
+    assert!(matches!(e, aoc_core::RunError::Part(aoc_core::Part::One, _)));
+    assert!(e.to_string().starts_with("part 1: 2:1: expected a digit, found \"eightwothree\""), "{}", e);
+}
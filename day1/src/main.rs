@@ -1,33 +1,63 @@
-/*
---- Day 1: Trebuchet?! ---
-Something is wrong with global snow production, and you've been selected to take a look. The Elves have even given you a map; on it, they've used stars to mark the top fifty locations that are likely to be having problems.
-
-You've been doing this long enough to know that to restore snow operations, you need to check all fifty stars by December 25th.
-
-Collect stars by solving puzzles. Two puzzles will be made available on each day in the Advent calendar; the second puzzle is unlocked when you complete the first. Each puzzle grants one star. Good luck!
-
-You try to ask why they can't just use a weather machine ("not powerful enough") and where they're even sending you ("the sky") and why your map looks mostly blank ("you sure ask a lot of questions") and hang on did you just say the sky ("of course, where do you think snow comes from") when you realize that the Elves are already loading you into a trebuchet ("please hold still, we need to strap you in").
-
-As they're making the final adjustments, they discover that their calibration document (your puzzle input) has been amended by a very young Elf who was apparently just excited to show off her art skills. Consequently, the Elves are having trouble reading the values on the document.
-
-The newly-improved calibration document consists of lines of text; each line originally contained a specific calibration value that the Elves now need to recover. On each line, the calibration value can be found by combining the first digit and the last digit (in that order) to form a single two-digit number.
-
-For example:
-
-1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet
-In this example, the calibration values of these four lines are 12, 38, 15, and 77. Adding these together produces 142.
-
-Consider your entire calibration document. What is the sum of all of the calibration values?
-*/
-
-mod first;
-mod second;
+// day1 [part] [input] answers the puzzle like every day. day1 explain [part] [input] shows the
+// tokens of each line instead, the digits of part 1 or 2 (2 by default), and day1 explain
+// --vocabulary <file> [input] those of a vocabulary file
+
+use std::env;
+use std::io::{self, IsTerminal};
+use aoc_core::{fail, flags, InputError, Part, Source};
+use day1::calibration::{self, CalibrationError};
+use day1::explain::report;
+use day1::vocabulary::Vocabulary;
+
+// the document is read one line at a time, it does not have to fit in memory
+fn answer() {
+    let (parts, source) = aoc_core::arguments(1);
+    let document = source.open().unwrap_or_else(|e| fail(e));
+    let totals = match calibration::totals(document) {
+        Ok(totals) => totals,
+        Err(CalibrationError::Parse(e)) => fail(e.in_file(&source)),
+        Err(CalibrationError::Read(error)) => fail(InputError { source, error }),
+    };
+    for part in parts {
+        match part {
+            Part::One => aoc_core::print_answer(part, totals.first.clone().unwrap_or_else(|e| fail(e.in_file(&source)))),
+            Part::Two => aoc_core::print_answer(part, totals.second),
+        }
+    }
+}
 
 fn main() {
-   let input = "day1/assets/input";
-   println!("first result {}", first::puzzle(input));
-   println!("second result {}", second::puzzle(input));
-}
\ No newline at end of file
+    let args: Vec<String> = env::args().collect();
+    if args.get(1).map(String::as_str) != Some("explain") {
+        return answer();
+    }
+
+    let mut vocabulary = None;
+    let rest = flags(args[2..].iter().map(String::as_str), |flag, value| match flag {
+        "--vocabulary" => {
+            vocabulary = Some(Vocabulary::load(value).map_err(|e| e.to_string())?);
+            Ok(())
+        }
+        _ => Err(format!("unknown flag {}", flag)),
+    });
+    let mut rest = rest.into_iter().peekable();
+    let vocabulary = vocabulary.unwrap_or_else(|| match rest.peek().map(|part| part.parse::<Part>()) {
+        Some(Ok(part)) => {
+            rest.next();
+            match part {
+                Part::One => Vocabulary::digits(),
+                Part::Two => Vocabulary::english(),
+            }
+        }
+        _ => Vocabulary::english(),
+    });
+
+    let source = Source::resolve(1, rest.next());
+    let document = source.open().unwrap_or_else(|e| fail(e));
+    let colour = io::stdout().is_terminal();
+    match report(&vocabulary, document, colour, io::stdout().lock()) {
+        // piped into head, there is no one left to explain it to
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => fail(e),
+        _ => {}
+    }
+}
@@ -16,88 +16,52 @@ In this example, the calibration values are 29, 83, 13, 24, 42, 14, and 76. Addi
 What is the sum of all of the calibration values?
 */
 
-use std::fs::read_to_string;
-use regex::Regex;
-use regex::RegexSet;
-use apply::Apply;
-use apply::Also;
+use std::sync::OnceLock;
+use crate::vocabulary::Vocabulary;
 
-pub fn puzzle(input: &str) -> u32 {
-    read_to_string(input).unwrap().lines()
-    .map(|l| extract_first_and_last_digit(l))
-    .fold::<u32, _>(0, |acc, (f,l)| {
-        acc + format!("{}{}", f, l).parse::<u32>().unwrap()
-    })
+// built once for every line of every document
+fn english() -> &'static Vocabulary {
+    static ENGLISH: OnceLock<Vocabulary> = OnceLock::new();
+    ENGLISH.get_or_init(Vocabulary::english)
 }
 
-fn str_to_digit(digit_str: &str) -> char {
-    match digit_str {
-        "zero" | "0" => '0',
-        "one" | "1" => '1',
-        "two" | "2" => '2',
-        "three" | "3" => '3',
-        "four" | "4" => '4',
-        "five" | "5" => '5',
-        "six" | "6" => '6',
-        "seven" | "7" => '7',
-        "eight" | "8" =>'8',
-        "nine" |"9" => '9',
-        _ => panic!("not a digit")
-    }
+/// the first and the last digits of the line, spelled or not, as a number. None when it has none
+pub fn calibration_value(line: &str) -> Option<u64> {
+    let (f, l) = extract_first_and_last_digit(line)?;
+    Some(f * 10 + l)
 }
 
-fn extract_first_and_last_digit(line: &str) -> (char,char) {
-    let patterns = ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
-    let set = RegexSet::new(patterns).unwrap();
-
-    set.matches(line)
-    .into_iter()
-    .flat_map(|index| {
-        let pattern = &patterns[index];
-        let regex = Regex::new(pattern).unwrap();
-        let ret: Vec<(usize,String)> = regex.captures_iter(line)
-        .map(|capture| {
-            let start = capture.get(0).unwrap().start().to_owned();
-            let matched_text = &capture[0];
-            (start, matched_text.to_owned())
-        })
-        .collect();
-        ret
-    })
-    .collect::<Vec<(usize,String)>>()
-    .also(|c| {
-        c.sort_by(|(a1,_),(a2,_)| a1.cmp(a2) );
-    })
-    .into_iter()
-    .map(|(_,s)| s)
-    .map(|s| str_to_digit(s.as_str()))
-    .collect::<Vec<char>>()
-    .apply(|o| (o.first().unwrap().clone(), o.last().unwrap().clone()))
+// the names are all of a single digit
+fn extract_first_and_last_digit(line: &str) -> Option<(u64, u64)> {
+    let (first, last) = english().first_and_last(line)?;
+    Some((english().number(first), english().number(last)))
 }
 
 
 #[test]
 fn test_1() {
     let strings = [
-        (('2','9'),"two1nine"),
-        (('8','3'), "eightwothree"),
-        (('1','3'), "abcone2threexyz"),
-        (('2','4'), "xtwone3four"),
-        (('4','2'), "4nineeightseven2"),
-        (('1','4'), "zoneight234"),
-        (('7','6'), "7pqrstsixteen"),
+        ((2, 9), "two1nine"),
+        ((8, 3), "eightwothree"),
+        ((1, 3), "abcone2threexyz"),
+        ((2, 4), "xtwone3four"),
+        ((4, 2), "4nineeightseven2"),
+        ((1, 4), "zoneight234"),
+        ((7, 6), "7pqrstsixteen"),
     ];
 
     for (expected,str) in strings {
-        assert_eq!(expected, extract_first_and_last_digit(str), "{}", str);
+        assert_eq!(Some(expected), extract_first_and_last_digit(str), "{}", str);
     }
 
     let res = strings
     .into_iter()
     .map(|(a,_)| a)
-    .fold::<u32, _>(0, |acc, (f,l)| {
-        acc + format!("{}{}", f, l).parse::<u32>().unwrap()
-    });
+    .fold::<u64, _>(0, |acc, (f,l)| acc + f * 10 + l);
 
     assert_eq!(281, res, "res is not correct");
-}
\ No newline at end of file
+    assert_eq!(None, extract_first_and_last_digit("onion"));
+    assert_eq!(Some((1, 8)), extract_first_and_last_digit("oneight"));
+    assert_eq!(Some((0, 1)), extract_first_and_last_digit("zerone"));
+    assert_eq!(Some(55), calibration_value("five"));
+}
@@ -0,0 +1,161 @@
+/*
+ * The tokens that stand for numbers in a calibration document: the numerals, and the words the
+ * document spells them with, in any language.
+ *
+ *   let french = Vocabulary::digits().with("un", 1).with("deux", 2).with("onze", 11);
+ *   french.calibration_value("xonzedeuxy")   // Some(12)
+ *
+ * or from a file with a token and its number on each line, the numerals are always tokens:
+ *
+ *   # french
+ *   un 1
+ *   onze 11
+ *
+ * a token of several digits writes all of them: the value of a line is the first digit of its
+ * first token and the last digit of its last token. tokens overlap ("oneight" is 1 then 8), the
+ * first token is the one that starts first and the last one the one that starts last. of the
+ * tokens that start at the same place, the longest is taken ("seventeen" rather than "seven").
+ */
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use aho_corasick::{AhoCorasick, Anchored, Input, Match, MatchKind, StartKind};
+use aoc_core::parse::number;
+use aoc_core::ParseError;
+use crate::calibration::CalibrationError;
+
+const DIGITS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+const ENGLISH: [&str; 10] = ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+#[derive(Debug, Clone)]
+pub struct Vocabulary {
+    tokens: BTreeMap<String, u64>,
+    // the numbers of the tokens, in the order of the patterns of the matcher
+    numbers: Vec<u64>,
+    matcher: AhoCorasick,
+}
+
+fn leading_digit(mut n: u64) -> u64 {
+    while n >= 10 {
+        n /= 10;
+    }
+    n
+}
+
+impl Vocabulary {
+    /// the tokens and the numbers they stand for, a token given twice stands for its last number
+    pub fn new<S: Into<String>>(tokens: impl IntoIterator<Item = (S, u64)>) -> Vocabulary {
+        let tokens: BTreeMap<String, u64> = tokens.into_iter().map(|(token, n)| (token.into(), n)).collect();
+        assert!(!tokens.contains_key(""), "a token cannot be empty");
+        let matcher = AhoCorasick::builder()
+        .match_kind(MatchKind::LeftmostLongest)
+        .start_kind(StartKind::Both)
+        .build(tokens.keys())
+        .expect("too many tokens");
+        Vocabulary { numbers: tokens.values().copied().collect(), tokens, matcher }
+    }
+
+    /// the numerals 0 to 9, the digits of part one
+    pub fn digits() -> Vocabulary {
+        Vocabulary::new(DIGITS.into_iter().zip(0..))
+    }
+
+    /// the numerals and their english names, the digits of part two
+    pub fn english() -> Vocabulary {
+        Vocabulary::new(DIGITS.into_iter().zip(0..).chain(ENGLISH.into_iter().zip(0..)))
+    }
+
+    /// the same vocabulary with one more token
+    pub fn with(self, token: impl Into<String>, n: u64) -> Vocabulary {
+        let mut tokens = self.tokens;
+        tokens.insert(token.into(), n);
+        Vocabulary::new(tokens)
+    }
+
+    /// a token and its number on each line, after the numerals. blank lines and the text after
+    /// a # are left out
+    pub fn parse(input: &str) -> Result<Vocabulary, ParseError> {
+        let mut tokens: Vec<(&str, u64)> = DIGITS.into_iter().zip(0..).collect();
+        for line in input.lines() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (token, n) = line.split_once(char::is_whitespace)
+            .ok_or_else(|| ParseError::at(input, &line[line.len()..], "a token and its number"))?;
+            tokens.push((token, number(input, n.trim_start(), "a number")?));
+        }
+        Ok(Vocabulary::new(tokens))
+    }
+
+    /// a vocabulary file, see parse
+    pub fn load(path: impl AsRef<Path>) -> Result<Vocabulary, CalibrationError> {
+        let path = path.as_ref();
+        let input = fs::read_to_string(path).map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+        Vocabulary::parse(&input).map_err(|e| CalibrationError::Parse(e.in_file(path.display())))
+    }
+
+    /// the longest token that starts at `at`
+    fn token_at(&self, line: &str, at: usize) -> Option<Match> {
+        self.matcher.find(Input::new(line).range(at..).anchored(Anchored::Yes))
+    }
+
+    /// every token of the line in order, the longest one where several start at the same place
+    pub fn tokens<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Match> + 'a {
+        (0..line.len()).filter_map(move |at| self.token_at(line, at))
+    }
+
+    /// the first and the last tokens of the line, None when it has none
+    pub fn first_and_last(&self, line: &str) -> Option<(Match, Match)> {
+        let first = self.matcher.find(line)?;
+        // from the end of the line, the first token is found again when there is no other
+        let last = (first.start()..line.len()).rev().find_map(|at| self.token_at(line, at))?;
+        Some((first, last))
+    }
+
+    /// the number a token found in a line stands for
+    pub fn number(&self, token: Match) -> u64 {
+        self.numbers[token.pattern().as_usize()]
+    }
+
+    /// the first digit of the first token and the last digit of the last token, as a number
+    pub fn calibration_value(&self, line: &str) -> Option<u64> {
+        let (first, last) = self.first_and_last(line)?;
+        Some(leading_digit(self.number(first)) * 10 + self.number(last) % 10)
+    }
+}
+
+#[test]
+fn test_1() {
+    let english = Vocabulary::english();
+    let example2 = include_str!("../assets/example2");
+    assert_eq!(281, example2.lines().map(|l| english.calibration_value(l).unwrap()).sum::<u64>());
+    assert_eq!(Some(18), english.calibration_value("oneight"));
+    assert_eq!(None, Vocabulary::digits().calibration_value("oneight"));
+
+    // "seventeen" is taken over "seven" where both start, "nine" starts after it
+    let teens = english.with("seventeen", 17).with("eleven", 11).with("sixty", 60);
+    assert_eq!(Some(17), teens.calibration_value("seventeen"));
+    assert_eq!(Some(19), teens.calibration_value("seventeenine"));
+    assert_eq!(Some(31), teens.calibration_value("x3eleven"));
+    assert_eq!(Some(60), teens.calibration_value("sixty"));
+    assert_eq!(Some(66), teens.calibration_value("sixtysix"));
+
+    let french = Vocabulary::load(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/french")).unwrap();
+    assert_eq!(Some(12), french.calibration_value("xonzedeuxy"));
+    assert_eq!(Some(18), french.calibration_value("premierneufhuit"));
+    assert_eq!(Some(10), french.calibration_value("dixneuf0"));
+    assert_eq!(Some(7), french.calibration_value("zéroseptx"));
+    assert_eq!(Some(23), french.calibration_value("deuxièmetrois"));
+}
+
+#[test]
+fn test_2() {
+    let e = Vocabulary::parse("un 1\n  deux\n").unwrap_err();
+    assert_eq!((2, 7, "a token and its number"), (e.line, e.column, e.expected.as_str()));
+    let e = Vocabulary::parse("# a comment\ntrois  x3 # three\n").unwrap_err();
+    assert_eq!((2, 8, "\"x3\""), (e.line, e.column, e.found.as_str()));
+    assert!(matches!(Vocabulary::load("no/such/vocabulary"), Err(CalibrationError::Read(_))));
+}
@@ -0,0 +1,463 @@
+/*
+--- Day 10: Pipe Maze ---
+You use the hang glider to ride the hot air from Desert Island all the way up to the floating metal island. This island is surprisingly cold and there definitely aren't any thermals to glide on, so you leave your hang glider behind.
+
+You wander around for a while, but you don't find any people or animals. However, you do occasionally find signposts labeled "Hot Springs" pointing in a seemingly consistent direction; maybe you can find someone at the hot springs and ask them where the desert-machine parts are made.
+
+The landscape here is alien; even the flowers and trees are made of metal. As you stop to admire some metal grass, you notice something metallic scurry away in your peripheral vision and jump into a big pipe! It didn't look like any animal you've ever seen; if you want a better look, you'll need to get ahead of it.
+
+Scanning the area, you discover that the entire field you're standing on is densely packed with pipes; it was hard to tell at first because they're the same metallic silver color as the "ground". You make a quick sketch of all of the surface pipes you can see (your puzzle input).
+
+The pipes are arranged in a two-dimensional grid of tiles:
+
+| is a vertical pipe connecting north and south.
+- is a horizontal pipe connecting east and west.
+L is a 90-degree bend connecting north and east.
+J is a 90-degree bend connecting north and west.
+7 is a 90-degree bend connecting south and west.
+F is a 90-degree bend connecting south and east.
+. is ground; there is no pipe in this tile.
+S is the starting position of the animal; there is a pipe on this tile, but your sketch doesn't show what shape the pipe has.
+Based on the acoustics of the animal's scurrying, you're confident the pipe that contains the animal is one large, continuous loop.
+
+For example, here is a square loop of pipe:
+
+.....
+.F-7.
+.|.|.
+.L-J.
+.....
+If the animal had entered this loop in the northwest corner, the sketch would instead look like this:
+
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+In the above diagram, the S tile is still a 90-degree F bend: you can tell because of how the adjacent pipes connect to it.
+
+Unfortunately, there are also many pipes that aren't connected to the loop! This sketch shows the same loop as above:
+
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF
+In the above diagram, you can still figure out which pipes form the main loop: they're the ones connected to S, pipes those pipes connect to, pipes those pipes connect to, and so on. Every pipe in the main loop connects to its two neighbors (including S, which will have exactly two pipes connecting to it, and which is assumed to connect back to those two pipes).
+
+Here is a sketch that contains a slightly more complex main loop:
+
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+Here's the same example sketch with the extra, non-main-loop pipe tiles also shown:
+
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ
+If you want to get out ahead of the animal, you should find the tile in the loop that is farthest from the starting position. Because the animal is in the pipe, it doesn't make sense to measure this by direct distance. Instead, you need to find the tile that would take the longest number of steps along the loop to reach from the starting point - regardless of which way around the loop the animal went.
+
+In the first example with the square loop:
+
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+You can count the distance each tile in the loop is from the starting point like this:
+
+.....
+.012.
+.1.3.
+.234.
+.....
+In this example, the farthest point from the start is 4 steps away.
+
+Here's the more complex loop again:
+
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+Here are the distances for each tile on that loop:
+
+..45.
+.236.
+01.78
+14567
+23...
+Find the single giant loop starting at S. How many steps along the loop does it take to get from the starting position to the point farthest from the starting position?
+
+--- Part Two ---
+You quickly reach the farthest point of the loop, but the animal never emerges. Maybe its nest is within the area enclosed by the loop?
+
+To determine whether it's even worth taking the time to search for such a nest, you should calculate how many tiles are contained within the loop. For example:
+
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+The above loop encloses merely four tiles - the two pairs of . in the southwest and southeast (marked I below). The middle . tiles (marked O below) are not in the loop. Here is the same loop again with those regions marked:
+
+...........
+.S-------7.
+.|F-----7|.
+.||OOOOO||.
+.||OOOOO||.
+.|L-7OF-J|.
+.|II|O|II|.
+.L--JOL--J.
+.....O.....
+In fact, there doesn't even need to be a full tile path to the outside for tiles to count as outside the loop - squeezing between pipes is also allowed! Here, I is still within the loop and O is still outside the loop:
+
+..........
+.S------7.
+.|F----7|.
+.||OOOO||.
+.||OOOO||.
+.|L-7F-J|.
+.|II||II|.
+.L--JL--J.
+..........
+In both of the above examples, 4 tiles are enclosed by the loop.
+
+Here's a larger example:
+
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+The above sketch has many random bits of ground, some of which are in the loop (I) and some of which are outside it (O):
+
+OF----7F7F7F7F-7OOOO
+O|F--7||||||||FJOOOO
+O||OFJ||||||||L7OOOO
+FJL7L7LJLJ||LJIL-7OO
+L--JOL7IIILJS7F-7L7O
+OOOOF-JIIF7FJ|L7L7L7
+OOOOL7IF7||L7|IL7L7|
+OOOOO|FJLJ|FJ|F7|OLJ
+OOOOFJL-7O||O||||OOO
+OOOOL---JOLJOLJLJOOO
+In this larger example, 8 tiles are enclosed by the loop.
+
+Any tile that isn't part of the main loop can count as being enclosed by the loop. Here's another example with many bits of junk pipe lying around that aren't connected to the main loop at all:
+
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+Here are just the tiles that are enclosed by the loop marked with I:
+
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJIF7FJ-
+L---JF-JLJIIIIFJLJJ7
+|F|F-JF---7IIIL7L|7|
+|FFJF7L7F-JF7IIL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+In this last example, 10 tiles are enclosed by the loop.
+
+Figure out whether you have time to search for the nest by calculating the area within the loop. How many tiles are enclosed by the loop?
+
+*/
+
+use std::collections::HashSet;
+use aoc_core::{ParseError, Picture, Solution};
+use aoc_grid::{Direction, Grid, Pos};
+use aoc_grid::render::{Color, Render, Style};
+use aoc_grid::search::flood_fill;
+
+// the direction we are heading to after going through a pipe, None when it does not let us in
+fn rotate(dir: Direction, c: char) -> Option<Direction> {
+  match (dir,c) {
+    (Direction::Up,'|') => Some(Direction::Up),
+    (Direction::Down,'|') => Some(Direction::Down),
+    (Direction::Left,'-') => Some(Direction::Left),
+    (Direction::Right,'-') => Some(Direction::Right),
+    (Direction::Down,'L') => Some(Direction::Right),
+    (Direction::Left,'L') => Some(Direction::Up),
+    (Direction::Right,'J') => Some(Direction::Up),
+    (Direction::Down,'J') => Some(Direction::Left),
+    (Direction::Right,'7') => Some(Direction::Down),
+    (Direction::Up,'7') => Some(Direction::Left),
+    (Direction::Up,'F') => Some(Direction::Right),
+    (Direction::Left,'F') => Some(Direction::Down),
+    _ => None
+  }
+}
+
+#[derive(Debug)]
+pub struct Maze {
+  start: Pos,
+  map: Grid<char>,
+  // the loop through S walked clockwise, from S, and the direction it leaves S in
+  path: Vec<Pos>,
+  dir: Direction,
+} 
+
+fn load_maze(input: &str) -> Result<Maze, ParseError> {
+  let map = Grid::parse(input, |c| "|-LJ7F.S".contains(c).then_some(c), "a pipe, '.' or 'S'")?;
+  let start = map.position(|&c| c == 'S').ok_or_else(|| ParseError::missing(input, "a starting position 'S'"))?;
+  // the pipe under S is unknown, it is the one of the two neighbours the loop leaves through
+  let loops: Vec<(Direction, Vec<Pos>)> = start_directions(&map, start)
+  .into_iter()
+  .filter_map(|d| walk_maze(&map, start, d).map(|path| (d, path)))
+  .collect();
+  if loops.len() < 2 {
+    return Err(ParseError::missing(input, "a loop through 'S'"));
+  }
+  // the inside of the loop is on the right only if we walk it clockwise
+  let (dir, path) = loops.into_iter().find(|(_, path)| is_clockwise(path))
+  .ok_or_else(|| ParseError::missing(input, "a loop through 'S'"))?;
+  Ok(Maze { start, map, path, dir })
+}
+
+// the neighbours of S with a pipe that points back at it, in or out of the loop
+fn start_directions(map: &Grid<char>, start: Pos) -> Vec<Direction> {
+  [
+    (Direction::Up, "|7F"),
+    (Direction::Right, "-J7"),
+    (Direction::Down, "|LJ"),
+    (Direction::Left, "-LF"),
+  ]
+  .into_iter()
+  .filter(|(d, pipes)| map.step(start, *d).is_some_and(|p| pipes.contains(map[p])))
+  .map(|(d, _)| d)
+  .collect()
+}
+
+// the pipes from S back to it, None when they lead out of the maze or into a pipe that does not
+// connect. a walk that does not reverse cannot come back to a pipe other than S
+fn walk_maze(map: &Grid<char>, start: Pos, dir: Direction) -> Option<Vec<Pos>> {
+  let mut path = vec![start];
+
+  let mut now = start;
+  let mut dir = dir;
+  loop {
+    now = map.step(now, dir)?;
+    
+    if now == start {
+      break;
+    }
+    
+    dir = rotate(dir, map[now])?;
+    path.push(now);
+  }
+
+  Some(path)
+}
+
+// box drawing characters are easier to follow than the letters
+fn pipe_glyph(c: &char) -> char {
+  match c {
+    '|' => '│',
+    '-' => '─',
+    'L' => '└',
+    'J' => '┘',
+    '7' => '┐',
+    'F' => '┌',
+    '.' => ' ',
+    c => *c
+  }
+}
+
+/*  ----------- part 2 ------------- */
+
+// shoelace formula, positive when the path goes clockwise (rows are pointing down)
+fn is_clockwise(path: &[Pos]) -> bool {
+  let n = path.len();
+  (0..n).map(|k| {
+    let ((i1, j1), (i2, j2)) = (path[k], path[(k+1) % n]);
+    (j1 * i2) as i64 - (j2 * i1) as i64
+  })
+  .sum::<i64>() > 0
+}
+
+// the loop and the cells it encloses
+fn color_right(maze: &Maze) -> (Vec<Pos>, HashSet<Pos>) {
+  // first we walk the loop again, clockwise, the inside is on its right
+  let path = maze.path.clone();
+  let mut dir = maze.dir;
+  let mut to_color= Vec::new();
+  for &now in &path[1..] {
+    // schedule to color right of border (before rotation)
+    to_color.push(maze.map.step(now, dir.turn_right()));
+
+    dir = rotate(dir, maze.map[now]).expect("the loop was walked when parsing");
+
+    // schedule to color right of border (after rotation)
+    to_color.push(maze.map.step(now, dir.turn_right()));
+  }
+   
+  // then we color the maze
+  let mut colored = maze.map.map(|_| '.');
+
+  for &p in path.iter() {
+    colored[p] = 'X';
+  }
+
+  let to_color: Vec<Pos> = to_color.into_iter()
+  .flatten()
+  .filter(|&c| { colored[c] == '.' })
+  .collect();
+
+  // the inside spreads until the border
+  let inside = flood_fill(to_color, |&c| {
+    colored.neighbours4(c).filter(|&c| colored[c] == '.').collect::<Vec<Pos>>()
+  });
+  (path, inside)
+}
+
+// the loop in yellow, the cells inside on green, the start in red
+fn draw_maze(maze: &Maze) -> Render<'_, char> {
+  let (path, inside) = color_right(maze);
+  Render::new(&maze.map, pipe_glyph)
+  .layer(maze.map.positions(), Style::fg(Color::Gray))
+  .layer(path, Style::fg(Color::Yellow).bold())
+  .layer(inside, Style::bg(Color::Green))
+  .layer([maze.start], Style::fg(Color::Red))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+  type Input = Maze;
+  type Answer1 = usize;
+  type Answer2 = usize;
+
+  fn parse(input: &str) -> Result<Maze, ParseError> {
+    load_maze(input)
+  }
+
+  fn part_one(maze: &Maze) -> usize {
+    maze.path.len()/2
+  }
+
+  fn part_two(maze: &Maze) -> usize {
+    color_right(maze).1.len()
+  }
+
+  fn visualize(maze: &Maze, show: &mut dyn FnMut(&str)) {
+    show(&draw_maze(maze).to_string());
+  }
+
+  fn pictures(maze: &Maze) -> Vec<(&'static str, Picture)> {
+    vec![("loop", draw_maze(maze).picture())]
+  }
+}
+
+#[test]
+fn test_1() {
+  let test_str = ".....
+.S-7.
+.|.|.
+.L-J.
+.....";
+  assert_eq!(4, Day10::part_one(&Day10::parse(test_str).unwrap()));
+}
+
+#[test]
+fn test_2() {
+  let test_str = "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+  assert_eq!(4, Day10::part_one(&Day10::parse(test_str).unwrap()));
+}
+
+#[test]
+fn test_3() {
+  let test_str = include_str!("../assets/example");
+  assert_eq!(8, Day10::part_one(&Day10::parse(test_str).unwrap()));
+}
+
+#[test]
+fn test_4() {
+  let test_str = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+  assert_eq!(4, Day10::part_two(&Day10::parse(test_str).unwrap()));
+}
+
+#[test]
+fn test_5() {
+  let test_str = "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+  assert_eq!(4, Day10::part_two(&Day10::parse(test_str).unwrap()));
+}
+
+#[test]
+fn test_6() {
+  let test_str = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+  assert_eq!(8, Day10::part_two(&Day10::parse(test_str).unwrap()));
+}
+
+#[test]
+fn test_7() {
+  let test_str = include_str!("../assets/example2");
+  assert_eq!(10, Day10::part_two(&Day10::parse(test_str).unwrap()));
+}
+
+#[test]
+fn test_8() {
+  // a pipe that points at S but is not part of the loop
+  let test_str = ".|...
+.S-7.
+.|.|.
+.L-J.
+.....";
+  let maze = Day10::parse(test_str).unwrap();
+  assert_eq!((4, 1), (Day10::part_one(&maze), Day10::part_two(&maze)));
+  let e = Day10::parse(".....\n.S-7.\n...|.\n.L-J.\n").unwrap_err();
+  assert_eq!("a loop through 'S'", e.expected);
+  assert!(Day10::parse(".|.\n.S.\n...").is_err());
+}
@@ -0,0 +1,277 @@
+/*
+--- Day 11: Cosmic Expansion ---
+You continue following signs for "Hot Springs" and eventually come across an observatory. The Elf within turns out to be a researcher studying cosmic expansion using the giant telescope here.
+
+He doesn't know anything about the missing machine parts; he's only visiting for this research project. However, he confirms that the hot springs are the next-closest area likely to have people; he'll even take you straight there once he's done with today's observation analysis.
+
+Maybe you can help him with the analysis to speed things up?
+
+The researcher has collected a bunch of data and compiled the data into a single giant image (your puzzle input). The image includes empty space (.) and galaxies (#). For example:
+
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+The researcher is trying to figure out the sum of the lengths of the shortest path between every pair of galaxies. However, there's a catch: the universe expanded in the time it took the light from those galaxies to reach the observatory.
+
+Due to something involving gravitational effects, only some space expands. In fact, the result is that any rows or columns that contain no galaxies should all actually be twice as big.
+
+In the above example, three columns and two rows contain no galaxies:
+
+   v  v  v
+ ...#......
+ .......#..
+ #.........
+>..........<
+ ......#...
+ .#........
+ .........#
+>..........<
+ .......#..
+ #...#.....
+   ^  ^  ^
+These rows and columns need to be twice as big; the result of cosmic expansion therefore looks like this:
+
+....#........
+.........#...
+#............
+.............
+.............
+........#....
+.#...........
+............#
+.............
+.............
+.........#...
+#....#.......
+Equipped with this expanded universe, the shortest path between every pair of galaxies can be found. It can help to assign every galaxy a unique number:
+
+....1........
+.........2...
+3............
+.............
+.............
+........4....
+.5...........
+............6
+.............
+.............
+.........7...
+8....9.......
+In these 9 galaxies, there are 36 pairs. Only count each pair once; order within the pair doesn't matter. For each pair, find any shortest path between the two galaxies using only steps that move up, down, left, or right exactly one . or # at a time. (The shortest path between two galaxies is allowed to pass through another galaxy.)
+
+For example, here is one of the shortest paths between galaxies 5 and 9:
+
+....1........
+.........2...
+3............
+.............
+.............
+........4....
+.5...........
+.##.........6
+..##.........
+...##........
+....##...7...
+8....9.......
+This path has length 9 because it takes a minimum of nine steps to get from galaxy 5 to galaxy 9 (the eight locations marked # plus the step onto galaxy 9 itself). Here are some other example shortest path lengths:
+
+Between galaxy 1 and galaxy 7: 15
+Between galaxy 3 and galaxy 6: 17
+Between galaxy 8 and galaxy 9: 5
+In this example, after expanding the universe, the sum of the shortest path between all 36 pairs of galaxies is 374.
+
+Expand the universe, then find the length of the shortest path between every pair of galaxies. What is the sum of these lengths?
+
+--- Part Two ---
+The galaxies are much older (and thus much farther apart) than the researcher initially estimated.
+
+Now, instead of the expansion you did before, make each empty row or column one million times larger. That is, each empty row should be replaced with 1000000 empty rows, and each empty column should be replaced with 1000000 empty columns.
+
+(In the example above, if each empty row or column were merely 10 times larger, the sum of the shortest paths between every pair of galaxies would be 1030. If each empty row or column were merely 100 times larger, the sum of the shortest paths between every pair of galaxies would be 8410. However, your universe will need to expand far beyond these values.)
+
+Starting with the same initial image, expand the universe according to these new rules, then find the length of the shortest path between every pair of galaxies. What is the sum of these lengths?
+
+ */
+
+use aoc_core::{ParseError, Solution};
+use aoc_grid::{Grid, Pos};
+use aoc_grid::render::{Color, Render, Style};
+
+pub type Universe = Grid<char>;
+
+fn load_universe(input: &str) -> Result<Universe, ParseError>  {
+  Grid::parse(input, |c| ".#".contains(c).then_some(c), "'.' or '#'")
+}
+
+fn empty_rows(u: &Universe) -> Vec<bool> {
+  u.rows().map(|r| r.iter().all(|c| *c == '.')).collect()
+}
+
+fn empty_cols(u: &Universe) -> Vec<bool> {
+  u.cols().map(|mut c| c.all(|c| *c == '.')).collect()
+}
+
+fn expand_universe(u: &Universe) -> Universe {
+  let (empty_rows, empty_cols) = (empty_rows(u), empty_cols(u));
+  let mut expanded = Vec::new();
+
+  for (i, line) in u.rows().enumerate() {
+    // expand vertically
+    let mut vline = Vec::new();
+    for (j, &c) in line.iter().enumerate() {
+      vline.push(c);
+      if empty_cols[j] {
+        vline.push(c);
+      }
+    }
+
+    // expand horizontally
+    if empty_rows[i] {
+      expanded.push(vline.clone());
+    }
+    expanded.push(vline);
+  }
+
+  Grid::from_rows(expanded)
+}
+
+// the galaxies in yellow, the empty lines that expand on blue
+fn draw_universe(u: &Universe) -> String {
+  let (empty_rows, empty_cols) = (empty_rows(u), empty_cols(u));
+  Render::new(u, |&c| c)
+  .layer_by(|(i, j), _| (empty_rows[i] || empty_cols[j]).then_some(Style::bg(Color::Blue)))
+  .layer_by(|_, &c| Some(if c == '#' { Style::fg(Color::Yellow).bold() } else { Style::fg(Color::Gray) }))
+  .to_string()
+}
+
+fn galaxies(u: &Universe) -> Vec<Pos> {
+  u.iter().filter(|(_, c)| **c == '#').map(|(p, _)| p).collect()
+}
+
+fn puzzle_one(u: &Universe) -> usize {
+  let galaxies = galaxies(u);
+
+  let mut sum = 0;
+  for i in 0..galaxies.len() {
+    for j in i..galaxies.len() {
+      if galaxies[i] == galaxies[j] {
+        continue;
+      }
+      let (i1,j1) = galaxies[i];
+      let (i2,j2) = galaxies[j];
+      let d = usize::max(i1,i2) - usize::min(i1,i2) + usize::max(j1,j2) - usize::min(j1,j2);
+      sum += d;
+    }
+  }
+
+  sum
+}
+
+/* ------- part two --------- */
+
+// instead of growing the universe we mark the empty lines with X
+fn expand_universe_2(u: &Universe) -> Universe {
+  let (empty_rows, empty_cols) = (empty_rows(u), empty_cols(u));
+  let mut expanded = u.clone();
+
+  for (i, j) in u.positions() {
+    if empty_rows[i] || empty_cols[j] {
+      expanded[(i, j)] = 'X';
+    }
+  }
+
+  expanded
+}
+
+
+fn puzzle_two(u: &Universe, factor: usize) -> usize {
+  let galaxies = galaxies(u);
+  // a galaxy is never marked, only the empty lines are full of X
+  let x_rows: Vec<bool> = u.rows().map(|r| r.iter().all(|c| *c == 'X')).collect();
+  let x_cols: Vec<bool> = u.cols().map(|mut c| c.all(|c| *c == 'X')).collect();
+
+  let mut sum = 0;
+  for i in 0..galaxies.len() {
+    for j in i..galaxies.len() {
+      if galaxies[i] == galaxies[j] {
+        continue;
+      }
+
+      let (i1,j1) = galaxies[i];
+      let (i2,j2) = galaxies[j];
+      for &x in &x_rows[usize::min(i1,i2)..usize::max(i1,i2)] {
+        if x {
+          sum += factor
+        } else {
+          sum += 1
+        }
+      }
+
+      for &x in &x_cols[usize::min(j1,j2)..usize::max(j1,j2)] {
+        if x {
+          sum += factor
+        } else {
+          sum += 1
+        }
+      }
+    }
+  }
+
+  sum
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+  type Input = Universe;
+  type Answer1 = usize;
+  type Answer2 = usize;
+
+  fn parse(input: &str) -> Result<Universe, ParseError> {
+    load_universe(input)
+  }
+
+  fn part_one(universe: &Universe) -> usize {
+    puzzle_one(&expand_universe(universe))
+  }
+
+  fn part_two(universe: &Universe) -> usize {
+    puzzle_two(&expand_universe_2(universe), 1000000)
+  }
+
+  fn visualize(universe: &Universe, show: &mut dyn FnMut(&str)) {
+    show(&draw_universe(universe));
+  }
+}
+
+#[test]
+fn test_1() {
+  let test_str = include_str!("../assets/example");
+  let input = Day11::parse(test_str).unwrap();
+  assert_eq!(374, Day11::part_one(&input));
+  assert_eq!(82000210, Day11::part_two(&input));
+}
+
+#[test]
+fn test_2() {
+  let test_str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+  let universe = expand_universe_2(&Day11::parse(test_str).unwrap());
+  assert_eq!(1030, puzzle_two(&universe, 10));
+  assert_eq!(8410, puzzle_two(&universe, 100));
+}
@@ -0,0 +1,207 @@
+/*
+--- Day 13: Point of Incidence ---
+With your help, the hot springs team locates an appropriate spring which launches you neatly and precisely up to the edge of Lava Island.
+
+There's just one problem: you don't see any lava.
+
+You do see a lot of ash and igneous rock; there are even what look like gray mountains scattered around. After a while, you make your way to a nearby cluster of mountains only to discover that the valley between them is completely full of large mirrors. Most of the mirrors seem to be aligned in a consistent way; perhaps you should head in that direction?
+
+As you move through the valley of mirrors, you find that several of them have fallen from the large metal frames keeping them in place. The mirrors are extremely flat and shiny, and many of the fallen mirrors have lodged into the ash at strange angles. Because the terrain is all one color, it's hard to tell where it's safe to walk or where you're about to run into a mirror.
+
+You note down the patterns of ash (.) and rocks (#) that you see as you walk (your puzzle input); perhaps by carefully analyzing these patterns, you can figure out where the mirrors are!
+
+For example:
+
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+To find the reflection in each pattern, you need to find a perfect reflection across either a horizontal line between two rows or across a vertical line between two columns.
+
+In the first pattern, the reflection is across a vertical line between two columns; arrows on each of the two columns point at the line between the columns:
+
+123456789
+    ><
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+    ><
+123456789
+In this pattern, the line of reflection is the vertical line between columns 5 and 6. Because the vertical line is not perfectly in the middle of the pattern, part of the pattern (column 1) has nowhere to reflect onto and can be ignored; every other column has a reflected column within the pattern and must match exactly: column 2 matches column 9, column 3 matches 8, 4 matches 7, and 5 matches 6.
+
+The second pattern reflects across a horizontal line instead:
+
+1 #...##..# 1
+2 #....#..# 2
+3 ..##..### 3
+4v#####.##.v4
+5^#####.##.^5
+6 ..##..### 6
+7 #....#..# 7
+This pattern reflects across the horizontal line between rows 4 and 5. Row 1 would reflect with a hypothetical row 8, but since that's not in the pattern, row 1 doesn't need to match anything. The remaining rows match: row 2 matches row 7, row 3 matches row 6, and row 4 matches row 5.
+
+To summarize your pattern notes, add up the number of columns to the left of each vertical line of reflection; to that, also add 100 multiplied by the number of rows above each horizontal line of reflection. In the above example, the first pattern's vertical line has 5 columns to its left and the second pattern's horizontal line has 4 rows above it, a total of 405.
+
+Find the line of reflection in each of the patterns in your notes. What number do you get after summarizing all of your notes?
+ */
+
+use aoc_core::{ParseError, Solution};
+use aoc_grid::Grid;
+
+type Pattern = Grid<char>;
+pub type Valley = Vec<Pattern>;
+
+fn load_valley(input: &str) -> Result<Valley, ParseError> {
+    input.split("\n\n")
+    .filter(|pattern| !pattern.trim().is_empty())
+    .map(|pattern| {
+        Grid::parse(pattern, |c| ".#".contains(c).then_some(c), "'.' or '#'")
+        .map_err(|e| e.within(input, pattern))
+    })
+    .collect::<Result<Valley, ParseError>>()
+}
+
+fn find_horizontal_mirror(p: &Pattern) -> Vec<usize> {
+    let mut ret = Vec::new();
+    let h = p.height();
+    for i in 1..=h/2 {
+        // upper half
+        if (0..i).all(|j| p.row(i-j-1) == p.row(i+j)) {
+            ret.push(i);
+        }
+        // bottom half
+        if (0..i).all(|j| p.row(h-1-i-j) == p.row(h-i+j)) {
+            ret.push(h-i);
+        }
+    }
+    ret
+}
+
+fn puzzle_part_one(valley: &Valley) -> usize {
+    let sum_h: usize = valley.iter()
+    .flat_map(find_horizontal_mirror)
+    .sum();
+
+    let sum_v: usize = valley.iter()
+    .map(Grid::transpose)
+    .flat_map(|p| find_horizontal_mirror(&p))
+    .sum();
+
+    100*sum_h+sum_v
+}
+
+/* ----------- part two ---------- */
+
+use std::collections::HashSet;
+use itertools::Itertools;
+
+#[derive(Debug)]
+enum Smudge {
+    Horizontal(usize),
+    Vertical(usize)
+}
+
+fn find_horizontal_smudge(mut p: Pattern) -> Vec<usize> {
+    let h = p.height();
+    let w = p.width();
+    
+    let without_smudge = find_horizontal_mirror(&p);
+    (0..h).cartesian_product(0..w).flat_map(|(i,j)| {
+        let c = p[(i,j)];
+        p[(i,j)] = if c == '.' { '#' } else { '.' };
+        let h = find_horizontal_mirror(&p);
+        p[(i,j)] = c;
+        h
+    })
+    .collect::<HashSet<_>>().into_iter()
+    .filter(|h| !without_smudge.contains(h))
+    .collect()
+}
+
+// None when no smudge gives a single new line of reflection
+fn find_smudge(p: &Pattern) -> Option<Smudge> {
+    let smudge = find_horizontal_smudge(p.clone());
+    if smudge.len() == 1 {
+        return Some(Smudge::Horizontal(smudge[0]));
+    }
+
+    let smudge = find_horizontal_smudge(p.transpose());
+    if smudge.len() == 1 {
+        return Some(Smudge::Vertical(smudge[0]));
+    }
+
+    None
+}
+
+fn puzzle_part_two(valley: &Valley) -> Result<usize, ParseError> {
+    valley.iter()
+    .enumerate()
+    .map(|(i, p)| find_smudge(p).ok_or_else(|| ParseError::whole("a smudge in every pattern", format!("none in pattern {}", i + 1))))
+    .try_fold(0, |acc,s| {
+        Ok(match s? {
+            Smudge::Horizontal(h) => acc + 100*h,
+            Smudge::Vertical(v) => acc + v,
+        })
+    })
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Valley;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Valley, ParseError> {
+        load_valley(input)
+    }
+
+    fn part_one(valley: &Valley) -> usize {
+        puzzle_part_one(valley)
+    }
+
+    fn part_two(valley: &Valley) -> usize {
+        Day13::try_part_two(valley).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    // a pattern that parses may have no smudge
+    fn try_part_two(valley: &Valley) -> Result<usize, ParseError> {
+        puzzle_part_two(valley)
+    }
+}
+
+#[test]
+fn test_1() {
+    let test_str = include_str!("../assets/example");
+    let input = Day13::parse(test_str).unwrap();
+    assert_eq!(405, Day13::part_one(&input));
+    assert_eq!(400, Day13::part_two(&input));
+}
+
+#[test]
+fn test_2() {
+    let e = Day13::parse("#.#\n.#.\n\n##.\n#o.\n").unwrap_err();
+    assert_eq!((5, 2, "\"o\""), (e.line, e.column, e.found.as_str()));
+}
+
+#[test]
+fn test_3() {
+    let input = Day13::parse(".").unwrap();
+    assert_eq!(0, Day13::part_one(&input));
+    assert_eq!("none in pattern 1", Day13::try_part_two(&input).unwrap_err().found);
+}
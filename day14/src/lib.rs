@@ -0,0 +1,186 @@
+/*
+--- Day 14: Parabolic Reflector Dish ---
+You reach the place where all of the mirrors were pointing: a massive parabolic reflector dish attached to the side of another large mountain.
+
+The dish is made up of many small mirrors, but while the mirrors themselves are roughly in the shape of a parabolic reflector dish, each individual mirror seems to be pointing in slightly the wrong direction. If the dish is meant to focus light, all it's doing right now is sending it in a vague direction.
+
+This system must be what provides the energy for the lava! If you focus the reflector dish, maybe you can go where it's pointing and use the light to fix the lava production.
+
+Upon closer inspection, the individual mirrors each appear to be connected via an elaborate system of ropes and pulleys to a large metal platform below the dish. The platform is covered in large rocks of various shapes. Depending on their position, the weight of the rocks deforms the platform, and the shape of the platform controls which ropes move and ultimately the focus of the dish.
+
+In short: if you move the rocks, you can focus the dish. The platform even has a control panel on the side that lets you tilt it in one of four directions! The rounded rocks (O) will roll when the platform is tilted, while the cube-shaped rocks (#) will stay in place. You note the positions of all of the empty spaces (.) and rocks (your puzzle input). For example:
+
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+Start by tilting the lever so all of the rocks will slide north as far as they will go:
+
+OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....
+You notice that the support beams along the north side of the platform are damaged; to ensure the platform doesn't collapse, you should calculate the total load on the north support beams.
+
+The amount of load caused by a single rounded rock (O) is equal to the number of rows from the rock to the south edge of the platform, including the row the rock is on. (Cube-shaped rocks (#) don't contribute to load.) So, the amount of load caused by each rock in each row is as follows:
+
+OOOO.#.O.. 10
+OO..#....#  9
+OO..O##..O  8
+O..#.OO...  7
+........#.  6
+..#....#.#  5
+..O..#.O.O  4
+..O.......  3
+#....###..  2
+#....#....  1
+The total load is the sum of the load caused by all of the rounded rocks. In this example, the total load is 136.
+
+Tilt the platform so that the rounded rocks all roll north. Afterward, what is the total load on the north support beams?
+ */
+
+use aoc_core::{cycle, ParseError, Picture, Solution};
+use aoc_grid::Grid;
+use aoc_grid::render::{Color, Render, Style};
+
+pub type Platform = Grid<char>;
+ 
+fn load_platform(input: &str) -> Result<Platform, ParseError> {
+    Grid::parse(input, |c| "O#.".contains(c).then_some(c), "'O', '#' or '.'")
+}
+
+// roll every rounded rock as far north as it goes
+fn tilt_north(p: &Platform) -> Platform {       
+    let mut ret = p.clone();
+    for j in 0..p.width() {
+        let mut rock = 0;
+        for i in 0..p.height() {
+            match p[(i,j)] {
+                'O' => {
+                    ret[(i,j)] = '.';
+                    ret[(rock,j)] = 'O';
+                    rock += 1;
+                }
+                '#' => {
+                    rock = i + 1;
+                },
+                _ => {}
+            }
+        }
+    }
+    ret
+}
+
+// north, west, south then east: each tilt is done north-wards on the
+// platform rotated clockwise, four rotations bring it back in place
+fn spin_cycle(p: &Platform) -> Platform {
+    (0..4).fold(p.clone(), |p, _| tilt_north(&p).rotate_clockwise())
+}
+
+fn weight(p: &Platform) -> usize {
+    let h = p.height();
+    p.rows().enumerate().map(|(i,line)| {
+        (h-i)*line.iter().filter(|&c| *c == 'O').count()
+    })
+    .sum()
+}
+
+fn puzzle_part_one(p: &Platform) -> usize {
+    weight(&tilt_north(p))
+}
+
+// the platform ends up going through the same positions over and over again,
+// a billion spin cycles land on one of the first few hundred
+fn puzzle_part_two(p: &Platform) -> usize {
+    weight(&cycle::nth(p, spin_cycle, 1000000000))
+}
+
+/* ---------- visualization ---------- */
+
+fn render_platform(p: &Platform) -> Render<'_, char> {
+    Render::new(p, |&c| c).layer_by(|_, &c| match c {
+        'O' => Some(Style::fg(Color::Yellow).bold()),
+        '#' => Some(Style::fg(Color::Gray)),
+        _ => None,
+    })
+}
+
+fn draw_platform(p: &Platform, caption: &str) -> String {
+    format!("{}{}\n", render_platform(p), caption)
+}
+
+// every tilt of the first spin cycles, then one frame per spin cycle until they loop
+fn animate(p: &Platform, show: &mut dyn FnMut(&str)) {
+    let cycle = cycle::brent(p, spin_cycle);
+    let cycles = cycle.prefix + cycle.period;
+    show(&draw_platform(p, &format!("load {}", weight(p))));
+
+    let mut p = p.clone();
+    for n in 1..=cycles {
+        let caption = |p: &Platform, tilt: &str| {
+            format!("spin cycle {} of {}{}, load {}", n, cycles, tilt, weight(p))
+        };
+        if n <= 2 {
+            // the platform is turned after each tilt, it is turned back to be drawn
+            for (k, side) in ["north", "west", "south", "east"].into_iter().enumerate() {
+                p = tilt_north(&p).rotate_clockwise();
+                let upright = (0..=k).fold(p.clone(), |p, _| p.rotate_counterclockwise());
+                show(&draw_platform(&upright, &caption(&upright, &format!(", tilted {}", side))));
+            }
+        } else {
+            p = spin_cycle(&p);
+            show(&draw_platform(&p, &caption(&p, "")));
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Platform;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Platform, ParseError> {
+        load_platform(input)
+    }
+
+    fn part_one(platform: &Platform) -> usize {
+        puzzle_part_one(platform)
+    }
+
+    fn part_two(platform: &Platform) -> usize {
+        puzzle_part_two(platform)
+    }
+
+    fn visualize(platform: &Platform, show: &mut dyn FnMut(&str)) {
+        animate(platform, show);
+    }
+
+    // tilted north for part one, and after a billion spin cycles
+    fn pictures(platform: &Platform) -> Vec<(&'static str, Picture)> {
+        let tilted = tilt_north(platform);
+        let spun = cycle::nth(platform, spin_cycle, 1000000000);
+        [("tilted", &tilted), ("spun", &spun)].into_iter().map(|(name, p)| (name, render_platform(p).picture())).collect()
+    }
+}
+
+#[test]
+fn test_1() {
+    let test_str = include_str!("../assets/example");
+    let input = Day14::parse(test_str).unwrap();
+    assert_eq!(136, Day14::part_one(&input));
+    assert_eq!(64, Day14::part_two(&input));
+}
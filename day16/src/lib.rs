@@ -0,0 +1,243 @@
+/*
+--- Day 16: The Floor Will Be Lava ---
+With the beam of light completely focused somewhere, the reindeer leads you deeper still into the Lava Production Facility. At some point, you realize that the steel facility walls have been replaced with cave, and the doorways are just cave, and the floor is cave, and you're pretty sure this is actually just a giant cave.
+
+Finally, as you approach what must be the heart of the mountain, you see a bright light in a cavern up ahead. There, you discover that the beam of light you so carefully focused is emerging from the cavern wall closest to the facility and pouring all of its energy into a contraption on the opposite side.
+
+Upon closer inspection, the contraption appears to be a flat, two-dimensional square grid containing empty space (.), mirrors (/ and \), and splitters (| and -).
+
+The contraption is aligned so that most of the beam bounces around the grid, but each tile on the grid converts some of the beam's light into heat to melt the rock in the cavern.
+
+You note the layout of the contraption (your puzzle input). For example:
+
+.|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+The beam enters in the top-left corner from the left and heading to the right. Then, its behavior depends on what it encounters as it moves:
+
+If the beam encounters empty space (.), it continues in the same direction.
+If the beam encounters a mirror (/ or \), the beam is reflected 90 degrees depending on the angle of the mirror. For instance, a rightward-moving beam that encounters a / mirror would continue upward in the mirror's column, while a rightward-moving beam that encounters a \ mirror would continue downward from the mirror's column.
+If the beam encounters the pointy end of a splitter (| or -), the beam passes through the splitter as if the splitter were empty space. For instance, a rightward-moving beam that encounters a - splitter would continue in the same direction.
+If the beam encounters the flat side of a splitter (| or -), the beam is split into two beams going in each of the two directions the splitter's pointy ends are pointing. For instance, a rightward-moving beam that encounters a | splitter would split into two beams: one that continues upward from the splitter's column and one that continues downward from the splitter's column.
+Beams do not interact with other beams; a tile can have many beams passing through it at the same time. A tile is energized if that tile has at least one beam pass through it, reflect in it, or split in it.
+
+In the above example, here is how the beam of light bounces around the contraption:
+
+>|<<<\....
+|v-.\^....
+.v...|->>>
+.v...v^.|.
+.v...v^...
+.v...v^..\
+.v../2\\..
+<->-/vv|..
+.|<<<2-|.\
+.v//.|.v..
+Beams are only shown on empty tiles; arrows indicate the direction of the beams. If a tile contains beams moving in multiple directions, the number of distinct directions is shown instead. Here is the same diagram but instead only showing whether a tile is energized (#) or not (.):
+
+######....
+.#...#....
+.#...#####
+.#...##...
+.#...##...
+.#...##...
+.#..####..
+########..
+.#######..
+.#...#.#..
+Ultimately, in this example, 46 tiles become energized.
+
+The light isn't energizing enough tiles to produce lava; to debug the contraption, you need to start by analyzing the current situation. With the beam starting in the top-left heading right, how many tiles end up being energized?
+
+Your puzzle answer was 8098.
+
+The first half of this puzzle is complete! It provides one gold star: *
+
+--- Part Two ---
+As you try to work out what might be wrong, the reindeer tugs on your shirt and leads you to a nearby control panel. There, a collection of buttons lets you align the contraption so that the beam enters from any edge tile and heading away from that edge. (You can choose either of two directions for the beam if it starts on a corner; for instance, if the beam starts in the bottom-right corner, it can start heading either left or upward.)
+
+So, the beam could start on any tile in the top row (heading downward), any tile in the bottom row (heading upward), any tile in the leftmost column (heading right), or any tile in the rightmost column (heading left). To produce lava, you need to find the configuration that energizes as many tiles as possible.
+
+In the above example, this can be achieved by starting the beam in the fourth tile from the left in the top row:
+
+.|<2<\....
+|v-v\^....
+.v.v.|->>>
+.v.v.v^.|.
+.v.v.v^...
+.v.v.v^..\
+.v.v/2\\..
+<-2-/vv|..
+.|<<<2-|.\
+.v//.|.v..
+Using this configuration, 51 tiles are energized:
+
+.#####....
+.#.#.#....
+.#.#.#####
+.#.#.##...
+.#.#.##...
+.#.#.##...
+.#.#####..
+########..
+.#######..
+.#...#.#..
+Find the initial beam configuration that energizes the largest number of tiles; how many tiles are energized in that configuration?
+ */
+
+use std::collections::{HashMap, HashSet};
+use aoc_core::{ParseError, Picture, Solution};
+use aoc_grid::{Direction, Grid, Pos};
+use aoc_grid::render::{Color, Render, Style};
+use aoc_grid::search::{bfs, flood_fill};
+
+pub type Contraption = Grid<char>;
+
+fn load_contraption(input: &str) -> Result<Contraption, ParseError> {
+    Grid::parse(input, |c| ".\\/-|".contains(c).then_some(c), "'.', a mirror or a splitter")
+}
+
+// directions the beam leaves a tile in, given the direction it entered with
+fn deflect(c: char, dir: Direction) -> Vec<Direction> {
+    match c {
+        '.'  => vec![dir],
+        '\\' => match dir {
+                    Direction::Up    => vec![Direction::Left],
+                    Direction::Down  => vec![Direction::Right],
+                    Direction::Left  => vec![Direction::Up],
+                    Direction::Right => vec![Direction::Down],
+        },
+        '/' => match dir {
+                    Direction::Up    => vec![Direction::Right],
+                    Direction::Down  => vec![Direction::Left],
+                    Direction::Left  => vec![Direction::Down],
+                    Direction::Right => vec![Direction::Up],
+        },
+        '-' => if dir.is_vertical() { vec![Direction::Left, Direction::Right] } else { vec![dir] },
+        '|' => if dir.is_vertical() { vec![dir] } else { vec![Direction::Up, Direction::Down] },
+        _ => {panic!("oh noes /o\\");}
+    }
+}
+
+// where the beam goes next from a tile, and the direction it goes in
+fn beam_moves(contraption: &Contraption, (pos, dir): (Pos, Direction)) -> impl Iterator<Item = (Pos, Direction)> + '_ {
+    deflect(contraption[pos], dir).into_iter()
+    .filter_map(move |next| contraption.step(pos, next).map(|beam| (beam, next)))
+}
+
+// the tiles a beam goes through, it stops once it only enters tiles the way it already did
+fn energized(contraption: &Contraption, entry_point: (Pos, Direction)) -> HashSet<Pos> {
+    let beams = flood_fill([entry_point], |&beam| beam_moves(contraption, beam));
+    beams.into_iter().map(|(pos, _)| pos).collect()
+}
+
+fn energy_level(contraption: &Contraption, entry_point: (Pos, Direction)) -> usize {
+    energized(contraption, entry_point).len()
+}
+
+fn puzzle_part_one(contraption: &Contraption) -> usize {
+    energy_level(contraption, ((0, 0), Direction::Right))
+}
+
+// every tile of the edges, heading inside
+fn entry_points(contraption: &Contraption) -> Vec<(Pos, Direction)> {
+    let (h, w) = (contraption.height(), contraption.width());
+    let mut configuration = Vec::new();
+    (0..w).for_each(|j| {
+        configuration.push(((0,j),Direction::Down));
+        configuration.push(((h-1,j),Direction::Up));
+    });
+    (0..h).for_each(|i| {
+        configuration.push(((i,0),Direction::Right));
+        configuration.push(((i,w-1),Direction::Left));
+    });
+    configuration
+}
+
+fn puzzle_part_two(contraption: &Contraption) -> usize {
+    entry_points(contraption).into_iter().map(|entry| energy_level(contraption, entry)).max().unwrap()
+}
+
+/* ---------- visualization ---------- */
+
+fn render_contraption<'a>(contraption: &'a Contraption, energized: &HashSet<Pos>) -> Render<'a, char> {
+    Render::new(contraption, |&c| c)
+    .layer_by(|_, &c| (c != '.').then_some(Style::fg(Color::Cyan).bold()))
+    .layer(energized.iter().copied(), Style::bg(Color::Red))
+}
+
+fn draw_contraption(contraption: &Contraption, energized: &HashSet<Pos>, caption: &str) -> String {
+    format!("{}{}\n", render_contraption(contraption, energized), caption)
+}
+
+fn best_entry_point(contraption: &Contraption) -> (Pos, Direction) {
+    entry_points(contraption).into_iter().max_by_key(|&entry| energy_level(contraption, entry)).unwrap()
+}
+
+// the beam of part one spreading through the contraption, then the entry that energizes the most
+fn animate(contraption: &Contraption, show: &mut dyn FnMut(&str)) {
+    // the first time the beam reaches each tile
+    let entry = ((0, 0), Direction::Right);
+    let search = bfs([entry], |&beam| beam_moves(contraption, beam).collect::<Vec<_>>(), |_| false);
+    let mut reached: HashMap<Pos, usize> = HashMap::new();
+    for (&(pos, _), steps) in search.reached() {
+        reached.entry(pos).and_modify(|s| *s = steps.min(*s)).or_insert(steps);
+    }
+
+    let last = reached.values().copied().max().unwrap_or(0);
+    let every = (last / 100).max(1);
+    for steps in (0..last).step_by(every).chain([last]) {
+        let tiles = reached.iter().filter(|(_, &s)| s <= steps).map(|(&p, _)| p).collect();
+        show(&draw_contraption(contraption, &tiles, &format!("{} tiles energized after {} steps", tiles.len(), steps)));
+    }
+
+    let best = best_entry_point(contraption);
+    let tiles = energized(contraption, best);
+    show(&draw_contraption(contraption, &tiles, &format!("{} tiles energized from {:?} heading {:?}", tiles.len(), best.0, best.1)));
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Contraption;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Contraption, ParseError> {
+        load_contraption(input)
+    }
+
+    fn part_one(contraption: &Contraption) -> usize {
+        puzzle_part_one(contraption)
+    }
+
+    fn part_two(contraption: &Contraption) -> usize {
+        puzzle_part_two(contraption)
+    }
+
+    fn visualize(contraption: &Contraption, show: &mut dyn FnMut(&str)) {
+        animate(contraption, show);
+    }
+
+    // the tiles energized by the beam of part one, and by the best one of part two
+    fn pictures(contraption: &Contraption) -> Vec<(&'static str, Picture)> {
+        let beam = energized(contraption, ((0, 0), Direction::Right));
+        let best = energized(contraption, best_entry_point(contraption));
+        vec![("beam", render_contraption(contraption, &beam).picture()), ("best", render_contraption(contraption, &best).picture())]
+    }
+}
+
+#[test]
+fn test_1() {
+    let test_str = include_str!("../assets/example");
+    let input = Day16::parse(test_str).unwrap();
+    assert_eq!(46, Day16::part_one(&input));
+    assert_eq!(51, Day16::part_two(&input));
+}
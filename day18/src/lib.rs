@@ -0,0 +1,262 @@
+/*
+--- Day 18: Lavaduct Lagoon ---
+Thanks to your efforts, the machine parts factory is one of the first factories up and running since the lavafall came back. However, to catch up with the large backlog of parts requests, the factory will also need a large supply of lava for a while; the Elves have already started creating a large lagoon nearby for this purpose.
+
+However, they aren't sure the lagoon will be big enough; they've asked you to take a look at the dig plan (your puzzle input). For example:
+
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+The digger starts in a 1 meter cube hole in the ground. They then dig the specified number of meters up (U), down (D), left (L), or right (R), clearing full 1 meter cubes as they go. The directions are given as seen from above, so if "up" were north, then "right" would be east, and so on. Each trench is also listed with the color that the edge of the trench should be painted as an RGB hexadecimal color code.
+
+When viewed from above, the above example dig plan would result in the following loop of trench (#) having been dug out from otherwise ground-level terrain (.):
+
+#######
+#.....#
+###...#
+..#...#
+..#...#
+###.###
+#...#..
+##..###
+.#....#
+.######
+At this point, the trench could contain 38 cubic meters of lava. However, this is just the edge of the lagoon; the next step is to dig out the interior so that it is one meter deep as well:
+
+#######
+#######
+#######
+..#####
+..#####
+#######
+#####..
+#######
+.######
+.######
+Now, the lagoon can contain a much more respectable 62 cubic meters of lava. While the interior is dug out, the edges are also painted according to the color codes in the dig plan.
+
+The Elves are concerned the lagoon won't be large enough; if they follow their dig plan, how many cubic meters of lava could it hold?
+
+--- Part Two ---
+The Elves were right to be concerned; the planned lagoon would be much too small.
+
+After a few minutes, someone realizes what happened; someone swapped the color and instruction parameters when producing the dig plan. They don't have time to fix the bug; one of them asks if you can extract the correct instructions from the hexadecimal codes.
+
+Each hexadecimal code is six hexadecimal digits long. The first five hexadecimal digits encode the distance in meters as a five-digit hexadecimal number. The last hexadecimal digit encodes the direction to dig: 0 means R, 1 means D, 2 means L, and 3 means U.
+
+So, in the above example, the hexadecimal codes can be converted into the true instructions:
+
+#70c710 = R 461937
+#0dc571 = D 56407
+#5713f0 = R 356671
+#d2c081 = D 863240
+#59c680 = R 367720
+#411b91 = D 266681
+#8ceee2 = L 577262
+#caa173 = U 829975
+#1b58a2 = L 112010
+#caa171 = D 829975
+#7807d2 = L 491645
+#a77fa3 = U 686074
+#015232 = L 5411
+#7a21e3 = U 500254
+Digging out this loop and its interior produces a lagoon that can hold an impressive 952408144115 cubic meters of lava.
+
+Convert the hexadecimal color codes into the correct instructions; if the Elves follow this new dig plan, how many cubic meters of lava could the lagoon hold?
+
+*/
+
+ use aoc_core::{debug, ParseError, Picture, Solution};
+ use aoc_core::parse::{number, split_once};
+ use aoc_grid::{Direction, Grid, Pos};
+ use aoc_grid::render::{Color, Render, Style};
+ use aoc_grid::search::flood_fill;
+
+type Plan = Vec<(Direction, usize, (u8,u8,u8))>;
+type Trenches = Grid<char>;
+type Path = Vec<(Pos,Direction)>;
+
+// a line like "R 6 (#70c710)", the colour is returned without its decoration
+fn load_line<'a>(input: &str, line: &'a str) -> Result<(Direction, usize, &'a str), ParseError> {
+    let (dir, rest) = split_once(input, line, " ")?;
+    let (length, color) = split_once(input, rest, " ")?;
+    let mut letters = dir.chars();
+    let dir = match (letters.next().and_then(Direction::from_letter), letters.next()) {
+        (Some(dir), None) => dir,
+        _ => return Err(ParseError::at(input, dir, "U, D, L or R")),
+    };
+    let length = number(input, length, "a length")?;
+    let hex = color.strip_prefix("(#").and_then(|c| c.strip_suffix(")"))
+    .filter(|h| h.len() == 6 && h.chars().all(|c| c.is_ascii_hexdigit()))
+    .ok_or_else(|| ParseError::at(input, color, "a colour like (#70c710)"))?;
+    Ok((dir, length, hex))
+}
+
+fn load_plan(input: &str) -> Result<Plan, ParseError> {
+    input.lines()
+    .map(|line| {
+        let (dir, length, hex) = load_line(input, line)?;
+        let r = u8::from_str_radix(&hex[0..2], 16).unwrap();
+        let g = u8::from_str_radix(&hex[2..4], 16).unwrap();
+        let b = u8::from_str_radix(&hex[4..6], 16).unwrap();
+        Ok((dir, length, (r,g,b)))
+    })
+    .collect::<Result<Vec<(Direction, usize, (u8,u8,u8))>, ParseError>>()
+}
+
+fn dig_trenches(plan: &Plan) -> (Trenches, Path) {
+    // first we get the dimension
+    let (mut i, mut j) = (0,0);
+    let (mut min_u,mut max_d,mut min_l,mut max_r) = (0,0,0,0);
+    plan.iter().for_each(|&(dir,length,_)| {
+        let (di,dj) = dir.offset();
+        (i,j) = (i + di*length as isize, j + dj*length as isize);
+        (min_u,max_d,min_l,max_r) = (isize::min(min_u,i),isize::max(max_d,i),isize::min(min_l,j),isize::max(max_r,j));
+    });
+
+    // then we dig
+    let mut ret_map = Grid::new((max_r-min_l+1) as usize, (max_d-min_u+1) as usize, '.');
+    let mut ret_path = Vec::new();
+    let mut pos = ((-min_u) as usize, (-min_l) as usize);
+    plan.iter().for_each(|&(dir,length,_)| {
+        (0..length).for_each(|_| {
+            ret_path.push((pos, dir));
+            ret_map[pos] = '#';
+            // the map was sized from the plan, every step lands inside it
+            pos = ret_map.step(pos, dir).unwrap();
+        });
+    });
+    (ret_map,ret_path)
+}
+
+fn paint_trenches((map,path): &(Trenches, Path)) -> Trenches {
+    // the plan goes clockwise, so the lagoon is on the right of every trench
+    let inside: Vec<Pos> = path.iter()
+    .filter_map(|&(pos, dir)| map.step(pos, dir.turn_right()))
+    .filter(|&inside| map[inside] == '.')
+    .collect();
+
+    let lagoon = flood_fill(inside, |&pos| map.neighbours4(pos).filter(|&p| map[p] == '.').collect::<Vec<Pos>>());
+    let mut map = map.clone();
+    lagoon.into_iter().for_each(|pos| map[pos] = '#');
+    map
+}
+
+// each trench in the colour of its line of the plan, the lagoon on blue
+fn render_lagoon<'a>(plan: &Plan, (trenches, path): &'a (Trenches, Path), lagoon: &Trenches) -> Render<'a, char> {
+    // the path has a step per meter of each line
+    let colors = plan.iter().flat_map(|&(_, length, (r,g,b))| std::iter::repeat_n(Color::Rgb(r,g,b), length));
+    path.iter().zip(colors).fold(Render::new(trenches, |&c| if c == '#' { '#' } else { ' ' }), |render, (&(pos, _), color)| {
+        render.layer([pos], Style::fg(color).bold())
+    })
+    .layer_by(|pos, &c| (c == '.' && lagoon[pos] == '#').then_some(Style::bg(Color::Blue)))
+}
+
+fn draw_trenches(plan: &Plan) -> String {
+    let dug = dig_trenches(plan);
+    let lagoon = paint_trenches(&dug);
+    format!("{}{} cubic meters of lava\n", render_lagoon(plan, &dug, &lagoon), lagoon.cells().filter(|&c| c == &'#').count())
+}
+
+/* part 2 */
+
+fn load_plan_2(input: &str) -> Result<Vec<(i64, i64)>, ParseError>{
+    let (mut x,mut y) = (0i64,0i64);
+    input.lines()
+    .map(|line| {
+        let (_, _, hex) = load_line(input, line)?;
+        let dst = u32::from_str_radix(&hex[0..5], 16).unwrap();
+        (x,y) = match &hex[5..6] {
+            "0" => (x+dst as i64, y),
+            "1" => (x,y+dst as i64),
+            "2" => (x-dst as i64,y),
+            "3" => (x,y-dst as i64),
+            dir => { return Err(ParseError::at(input, dir, "a direction from 0 to 3")); }
+        };
+        Ok((x,y))
+    })
+    .collect::<Result<Vec<(i64, i64)>, ParseError>>()
+}
+
+
+// both parts read the same lines, but not the same way
+#[derive(Debug)]
+pub struct DigPlan {
+    plan: Plan,
+    vertices: Vec<(i64, i64)>,
+}
+
+fn puzzle_part_one(plan: &Plan) -> usize {
+    let trenches = dig_trenches(plan);
+    let painted = paint_trenches(&trenches);
+    painted.cells().filter(|&c| c == &'#').count()
+}
+
+fn puzzle_part_two(plan: &[(i64, i64)]) -> i64 {
+    plan.iter().fold((0,0,0), |(acc,x1,y1),&(x2,y2)| {
+        debug!("vertex ({},{}) -> ({},{})",x1,y1,x2,y2);
+        // shoelace formulae + width of the line (distance)
+        let acc = acc + x1*y2-x2*y1 + ((((x2-x1)*(x2-x1)+(y2-y1)*(y2-y1)) as f64).sqrt()) as i64;
+        (acc,x2,y2)
+    }).0/2 + 1
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = DigPlan;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<DigPlan, ParseError> {
+        Ok(DigPlan { plan: load_plan(input)?, vertices: load_plan_2(input)? })
+    }
+
+    fn part_one(dig_plan: &DigPlan) -> usize {
+        puzzle_part_one(&dig_plan.plan)
+    }
+
+    fn part_two(dig_plan: &DigPlan) -> i64 {
+        puzzle_part_two(&dig_plan.vertices)
+    }
+
+    fn visualize(dig_plan: &DigPlan, show: &mut dyn FnMut(&str)) {
+        show(&draw_trenches(&dig_plan.plan));
+    }
+
+    fn pictures(dig_plan: &DigPlan) -> Vec<(&'static str, Picture)> {
+        let dug = dig_trenches(&dig_plan.plan);
+        let lagoon = paint_trenches(&dug);
+        let picture = render_lagoon(&dig_plan.plan, &dug, &lagoon).picture();
+        vec![("lagoon", picture)]
+    }
+}
+
+#[test]
+fn test_1() {
+    let test_str = include_str!("../assets/example");
+    let input = Day18::parse(test_str).unwrap();
+    assert_eq!(62, Day18::part_one(&input));
+    assert_eq!(952408144115, Day18::part_two(&input));
+}
+
+#[test]
+fn test_2() {
+    let e = Day18::parse("R 6 (#70c710)\nD 5 (#0dc571\n").unwrap_err();
+    assert_eq!((2, 5, "\"(#0dc571\""), (e.line, e.column, e.found.as_str()));
+    let e = Day18::parse("R 6 (#70c710)\nX 5 (#0dc571)").unwrap_err();
+    assert_eq!((2, 1), (e.line, e.column));
+    let e = Day18::parse("R 6 (#70c714)").unwrap_err();
+    assert_eq!((1, 12, "a direction from 0 to 3"), (e.line, e.column, e.expected.as_str()));
+}
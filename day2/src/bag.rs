@@ -0,0 +1,90 @@
+/*
+ * Which games could have been played with a bag of cubes, and why the others could not.
+ *
+ *   let bag = parse_cubes(text, text)?;   // "12 red, 13 green, 14 blue"
+ *   let possible = query(&bag, &games).iter().filter(|v| v.possible()).count();
+ *
+ * a game is impossible when one of its reveals shows more cubes of a colour than the bag has,
+ * a colour that is not in the bag has none. every verdict also gives the fewest cubes the game
+ * could have been played with, the bag part two is about.
+ */
+
+use std::fmt::{self, Display};
+use crate::game::{Cubes, Game};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// the index of the reveal in the game
+    pub reveal: usize,
+    pub colour: String,
+    pub shown: u32,
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verdict<'a> {
+    pub game: &'a Game,
+    /// the first reveal that shows more cubes than the bag has, None when the game is possible
+    pub violation: Option<Violation>,
+    pub minimal_bag: Cubes,
+}
+
+impl Verdict<'_> {
+    pub fn possible(&self) -> bool {
+        self.violation.is_none()
+    }
+}
+
+impl Display for Verdict<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.violation {
+            None => write!(f, "game {}: possible", self.game.id)?,
+            Some(v) => write!(
+                f,
+                "game {}: impossible, reveal {} shows {} {} and the bag has {}",
+                self.game.id, v.reveal + 1, v.shown, v.colour, v.limit
+            )?,
+        }
+        write!(f, ", needs at least {}", self.minimal_bag)
+    }
+}
+
+pub fn check<'a>(bag: &Cubes, game: &'a Game) -> Verdict<'a> {
+    let violation = game.reveals.iter().enumerate().find_map(|(i, reveal)| {
+        reveal.iter()
+        .find(|&(colour, shown)| shown > bag.count(colour))
+        .map(|(colour, shown)| Violation { reveal: i, colour: colour.to_string(), shown, limit: bag.count(colour) })
+    });
+    Verdict { game, violation, minimal_bag: game.minimal_bag() }
+}
+
+/// the verdicts of the games, in order
+pub fn query<'a>(bag: &Cubes, games: &'a [Game]) -> Vec<Verdict<'a>> {
+    games.iter().map(|game| check(bag, game)).collect()
+}
+
+#[test]
+fn test_1() {
+    use crate::game::{parse_cubes, parse_games};
+
+    let games = parse_games(include_str!("../assets/example")).unwrap();
+    let bag = Cubes::new([("red", 12), ("green", 13), ("blue", 14)]);
+    let verdicts = query(&bag, &games);
+    assert_eq!(vec![1, 2, 5], verdicts.iter().filter(|v| v.possible()).map(|v| v.game.id).collect::<Vec<_>>());
+    assert_eq!(
+        Some(Violation { reveal: 2, colour: String::from("blue"), shown: 15, limit: 14 }),
+        verdicts[3].violation
+    );
+    assert_eq!("game 1: possible, needs at least 6 blue, 2 green, 4 red", verdicts[0].to_string());
+    assert_eq!(
+        "game 3: impossible, reveal 1 shows 20 red and the bag has 12, needs at least 6 blue, 13 green, 20 red",
+        verdicts[2].to_string()
+    );
+
+    // no green cube at all, and a colour the games never show
+    let text = "20 red, 15 blue, 1 pink";
+    let bag = parse_cubes(text, text).unwrap();
+    let verdicts = query(&bag, &games);
+    assert_eq!(Some(0), verdicts[1].violation.as_ref().map(|v| v.limit));
+    assert!(verdicts.iter().all(|v| v.violation.as_ref().is_some_and(|v| v.colour == "green")));
+}
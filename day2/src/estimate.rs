@@ -0,0 +1,273 @@
+/*
+ * What the bag of a game most likely held, beyond the fewest cubes part two asks for.
+ *
+ * the elf draws each handful uniformly without replacement: a reveal of k cubes from a bag of
+ * N_c cubes of each colour, T in all, has a probability of
+ *
+ *   prod_c C(N_c, x_c) / C(T, k)
+ *
+ * the bags are weighted by the probability of all the reveals of the game. the likelihood alone
+ * does not bound the bag (a game that only shows red cubes is as likely with 3 of them as with
+ * 300), so every colour is given a uniform prior up to `max_cubes`: the intervals are the credible
+ * intervals of that posterior, and they reach max_cubes when the reveals say little.
+ *
+ * the most likely bag is a single bag, the intervals are for each colour on its own: the many
+ * larger bags together can outweigh the most likely one, and an interval may start above it.
+ *
+ * every bag from the minimal one up to max_cubes of each colour is weighted, for the colours the
+ * game shows: 3 colours and max_cubes 40 are 64000 bags, each more colour multiplies them. a game
+ * with more than MAX_BAGS of them, or with bags of more than MAX_CUBES in all, is an error.
+ */
+
+use std::fmt::{self, Display};
+use std::ops::RangeInclusive;
+use crate::game::Game;
+
+/// the most bags an estimate weighs
+pub const MAX_BAGS: u64 = 10_000_000;
+/// the most cubes in all a bag may hold, the logarithms of the factorials are kept up to it
+pub const MAX_CUBES: u64 = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Model {
+    /// the most cubes of a colour the bag may hold, more when a reveal shows more
+    pub max_cubes: u32,
+    /// the share of the posterior inside each interval, between 0 and 1
+    pub confidence: f64,
+}
+
+impl Default for Model {
+    fn default() -> Model {
+        Model { max_cubes: 40, confidence: 0.9 }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EstimateError {
+    /// the bags of the game to weigh, more than MAX_BAGS
+    TooManyBags(u64),
+    /// the cubes in all of the largest bag, more than MAX_CUBES
+    TooManyCubes(u64),
+}
+
+impl Display for EstimateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EstimateError::TooManyBags(n) => write!(f, "{} bags to weigh, at most {} are, lower max_cubes", n, MAX_BAGS),
+            EstimateError::TooManyCubes(n) => write!(f, "bags of {} cubes in all, at most {} are weighed", n, MAX_CUBES),
+        }
+    }
+}
+
+impl std::error::Error for EstimateError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+    pub colour: String,
+    /// the most cubes of the colour a reveal shows
+    pub minimum: u32,
+    /// the count of the colour in the most likely bag
+    pub most_likely: u32,
+    pub mean: f64,
+    /// the counts holding `confidence` of the posterior, the same share left out on each side
+    pub interval: (u32, u32),
+    pub confidence: f64,
+}
+
+impl Display for Estimate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: at least {}, most likely {}, mean {:.1}, {}% between {} and {}",
+            self.colour, self.minimum, self.most_likely, self.mean,
+            self.confidence * 100.0, self.interval.0, self.interval.1
+        )
+    }
+}
+
+// ln C(n, k) from the logarithms of the factorials
+fn ln_choose(ln_factorials: &[f64], n: u64, k: u64) -> f64 {
+    if k > n {
+        f64::NEG_INFINITY
+    } else {
+        ln_factorials[n as usize] - ln_factorials[k as usize] - ln_factorials[(n - k) as usize]
+    }
+}
+
+// every bag with the count of each colour in its range, like an odometer
+fn for_each_bag(ranges: &[RangeInclusive<u32>], mut visit: impl FnMut(&[u32])) {
+    let mut bag: Vec<u32> = ranges.iter().map(|r| *r.start()).collect();
+    loop {
+        visit(&bag);
+        let Some(i) = (0..bag.len()).rev().find(|&i| bag[i] < *ranges[i].end()) else {
+            return;
+        };
+        bag[i] += 1;
+        for j in i + 1..bag.len() {
+            bag[j] = *ranges[j].start();
+        }
+    }
+}
+
+/// the estimates of the colours the game shows, in alphabetical order
+pub fn estimate(game: &Game, model: &Model) -> Result<Vec<Estimate>, EstimateError> {
+    let minimal_bag = game.minimal_bag();
+    let colours: Vec<(&str, u32)> = minimal_bag.iter().collect();
+    let ranges: Vec<RangeInclusive<u32>> = colours.iter().map(|&(_, least)| least..=model.max_cubes.max(least)).collect();
+    // the sizes are checked before anything is allocated, a count that overflows is too many
+    let bags = ranges.iter().try_fold(1u64, |bags, r| bags.checked_mul((r.end() - r.start()) as u64 + 1));
+    match bags {
+        Some(bags) if bags <= MAX_BAGS => {}
+        _ => return Err(EstimateError::TooManyBags(bags.unwrap_or(u64::MAX))),
+    }
+    let most_in_all: u64 = ranges.iter().map(|r| *r.end() as u64).sum();
+    if most_in_all > MAX_CUBES {
+        return Err(EstimateError::TooManyCubes(most_in_all));
+    }
+    let most = ranges.iter().map(|r| *r.end()).max().unwrap_or(0);
+    let ln_factorials: Vec<f64> = (0..=most_in_all)
+    .scan(0.0, |ln, n| {
+        *ln += (n.max(1) as f64).ln();
+        Some(*ln)
+    })
+    .collect();
+
+    // the log likelihood of a bag splits into a term per colour and count, for the cubes shown,
+    // and a term per number of cubes in all, for the handfuls drawn from them
+    let shown: Vec<Vec<f64>> = colours.iter().map(|&(colour, _)| {
+        (0..=most as u64).map(|n| game.reveals.iter().map(|r| ln_choose(&ln_factorials, n, r.count(colour) as u64)).sum()).collect()
+    })
+    .collect();
+    let handfuls: Vec<u64> = game.reveals.iter().map(|r| r.iter().map(|(_, n)| n as u64).sum()).collect();
+    let drawn: Vec<f64> = (0..=most_in_all)
+    .map(|all| handfuls.iter().map(|&k| ln_choose(&ln_factorials, all, k)).sum())
+    .collect();
+    let ln_likelihood = |bag: &[u32]| {
+        let all: u64 = bag.iter().map(|&n| n as u64).sum();
+        bag.iter().enumerate().map(|(c, &n)| shown[c][n as usize]).sum::<f64>() - drawn[all as usize]
+    };
+
+    let (mut best, mut most_likely) = (f64::NEG_INFINITY, Vec::new());
+    for_each_bag(&ranges, |bag| {
+        let ln = ln_likelihood(bag);
+        if ln > best {
+            (best, most_likely) = (ln, bag.to_vec());
+        }
+    });
+    // the weights are scaled by the most likely bag, so that they do not all round to 0
+    let mut marginals: Vec<Vec<f64>> = vec![vec![0.0; most as usize + 1]; colours.len()];
+    for_each_bag(&ranges, |bag| {
+        let weight = (ln_likelihood(bag) - best).exp();
+        for (c, &n) in bag.iter().enumerate() {
+            marginals[c][n as usize] += weight;
+        }
+    });
+
+    let tail = (1.0 - model.confidence) / 2.0;
+    let estimates = colours.iter().zip(marginals).enumerate().map(|(c, (&(colour, minimum), marginal))| {
+        let total: f64 = marginal.iter().sum();
+        let mean = marginal.iter().enumerate().map(|(n, w)| n as f64 * w).sum::<f64>() / total;
+        // the first count at which the cumulated posterior reaches a share of it, among the
+        // counts it has weight for: a share of 0 is reached before the first of them
+        let quantile = |share: f64| {
+            let mut cumulated = 0.0;
+            marginal.iter().position(|&w| {
+                cumulated += w;
+                w > 0.0 && cumulated >= share * total * (1.0 - 1e-12)
+            })
+            .unwrap_or(marginal.len() - 1) as u32
+        };
+        Estimate {
+            colour: colour.to_string(),
+            minimum,
+            most_likely: most_likely[c],
+            mean,
+            interval: (quantile(tail), quantile(1.0 - tail)),
+            confidence: model.confidence,
+        }
+    })
+    .collect();
+    Ok(estimates)
+}
+
+#[test]
+fn test_1() {
+    use crate::game::parse_game;
+
+    // a bag of one red and one blue cube always shows both of them
+    let game = parse_game("Game 1: 1 red, 1 blue", "Game 1: 1 red, 1 blue").unwrap();
+    let estimates = estimate(&game, &Model::default()).unwrap();
+    assert_eq!(vec!["blue", "red"], estimates.iter().map(|e| e.colour.as_str()).collect::<Vec<_>>());
+    assert!(estimates.iter().all(|e| e.minimum == 1 && e.most_likely == 1 && e.interval.1 > e.interval.0));
+
+    // red alone says nothing of the size of the bag, every count up to max_cubes is as likely
+    let game = parse_game("Game 2: 3 red; 2 red", "Game 2: 3 red; 2 red").unwrap();
+    let estimates = estimate(&game, &Model { max_cubes: 10, confidence: 0.9 }).unwrap();
+    assert_eq!((3, 3, (3, 10)), (estimates[0].minimum, estimates[0].most_likely, estimates[0].interval));
+    assert!((estimates[0].mean - 6.5).abs() < 1e-9);
+    assert_eq!("red: at least 3, most likely 3, mean 6.5, 90% between 3 and 10", estimates[0].to_string());
+}
+
+#[test]
+fn test_2() {
+    use crate::game::parse_game;
+
+    // mostly red handfuls: more red than blue in the bag, and more than the fewest
+    let line = "Game 3: 5 red, 1 blue; 4 red, 2 blue; 6 red; 5 red, 1 blue";
+    let estimates = estimate(&parse_game(line, line).unwrap(), &Model::default()).unwrap();
+    let (blue, red) = (&estimates[0], &estimates[1]);
+    assert_eq!((2, 6), (blue.minimum, red.minimum));
+    assert!(red.most_likely > blue.most_likely);
+    assert!(red.mean > red.minimum as f64 && red.mean > 2.0 * blue.mean);
+    for e in &estimates {
+        assert!(e.minimum <= e.interval.0 && e.interval.0 <= e.interval.1 && e.interval.1 <= 40);
+    }
+
+    // a handful of 2 red then one of 1 blue, from 2 or 3 red and 1 to 3 blue cubes: the
+    // likelihood of a bag is C(red, 2) / C(all, 2) * blue / all
+    let game = parse_game("Game 4: 2 red; 1 blue", "Game 4: 2 red; 1 blue").unwrap();
+    let estimates = estimate(&game, &Model { max_cubes: 3, confidence: 0.5 }).unwrap();
+    let likelihood = |red: f64, blue: f64| {
+        let all = red + blue;
+        red * (red - 1.0) / (all * (all - 1.0)) * blue / all
+    };
+    let bags = [(2.0, 1.0), (2.0, 2.0), (2.0, 3.0), (3.0, 1.0), (3.0, 2.0), (3.0, 3.0)];
+    let total: f64 = bags.iter().map(|&(r, b)| likelihood(r, b)).sum();
+    let mean_blue = bags.iter().map(|&(r, b)| b * likelihood(r, b)).sum::<f64>() / total;
+    let mean_red = bags.iter().map(|&(r, b)| r * likelihood(r, b)).sum::<f64>() / total;
+    let (blue, red) = (&estimates[0], &estimates[1]);
+    assert_eq!((1, 3), (blue.most_likely, red.most_likely));
+    assert!((blue.mean - mean_blue).abs() < 1e-9 && (red.mean - mean_red).abs() < 1e-9, "{:?}", estimates);
+    assert_eq!(((1, 3), (2, 3)), (blue.interval, red.interval));
+}
+
+#[test]
+fn test_3() {
+    use crate::game::parse_games;
+
+    // all of the posterior lies between the fewest cubes and max_cubes, none of it below
+    let games = parse_games(include_str!("../assets/example")).unwrap();
+    for e in estimate(&games[0], &Model { max_cubes: 20, confidence: 1.0 }).unwrap() {
+        assert_eq!((e.minimum, 20), e.interval, "{}", e);
+    }
+    // no share at all is the median on both sides
+    for e in estimate(&games[0], &Model { max_cubes: 20, confidence: 0.0 }).unwrap() {
+        assert!(e.minimum <= e.interval.0 && e.interval.0 == e.interval.1, "{}", e);
+    }
+}
+
+#[test]
+fn test_4() {
+    use crate::game::parse_game;
+
+    // too large to weigh, whether by the reveals or by max_cubes
+    let line = "Game 1: 4000000000 red, 4000000000 blue";
+    let e = estimate(&parse_game(line, line).unwrap(), &Model::default()).unwrap_err();
+    assert_eq!(EstimateError::TooManyCubes(8_000_000_000), e);
+    let line = "Game 2: 3 red, 1 blue, 2 green";
+    let e = estimate(&parse_game(line, line).unwrap(), &Model { max_cubes: 100_000, confidence: 0.9 }).unwrap_err();
+    assert!(matches!(e, EstimateError::TooManyBags(n) if n > MAX_BAGS), "{:?}", e);
+    let line = "Game 3: 3 red, 1 blue, 2 green, 1 a, 1 b, 1 c, 1 d, 1 e";
+    let e = estimate(&parse_game(line, line).unwrap(), &Model { max_cubes: u32::MAX, confidence: 0.9 }).unwrap_err();
+    assert_eq!(EstimateError::TooManyBags(u64::MAX), e);
+}
@@ -0,0 +1,112 @@
+/*
+ * The record of a game: its id and the cubes of each reveal, counted by colour.
+ *
+ *   Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+ *
+ * any word is a colour, the puzzle only uses red, green and blue. a colour shown twice in a
+ * reveal counts the cubes of both.
+ */
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use aoc_core::ParseError;
+use aoc_core::parse::{number, split_once};
+
+/// cubes counted by colour, a colour that is not there has none
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cubes(BTreeMap<String, u32>);
+
+pub type Reveal = Cubes;
+
+impl Cubes {
+    pub fn new<S: Into<String>>(cubes: impl IntoIterator<Item = (S, u32)>) -> Cubes {
+        let mut ret = Cubes::default();
+        for (colour, count) in cubes {
+            ret.add(colour, count);
+        }
+        ret
+    }
+
+    pub fn add(&mut self, colour: impl Into<String>, count: u32) {
+        *self.0.entry(colour.into()).or_default() += count;
+    }
+
+    pub fn count(&self, colour: &str) -> u32 {
+        self.0.get(colour).copied().unwrap_or(0)
+    }
+
+    /// the colours in alphabetical order, with their counts
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> + '_ {
+        self.0.iter().map(|(colour, &count)| (colour.as_str(), count))
+    }
+}
+
+// the way a reveal is written down, "3 blue, 4 red"
+impl Display for Cubes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cubes: Vec<String> = self.iter().map(|(colour, count)| format!("{} {}", count, colour)).collect();
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub reveals: Vec<Reveal>,
+}
+
+impl Game {
+    /// the fewest cubes of each colour the bag had for the game to be played
+    pub fn minimal_bag(&self) -> Cubes {
+        let mut bag = BTreeMap::new();
+        for (colour, count) in self.reveals.iter().flat_map(Cubes::iter) {
+            let most = bag.entry(colour.to_string()).or_default();
+            *most = count.max(*most);
+        }
+        Cubes(bag)
+    }
+}
+
+/// cubes written like a reveal, "3 blue, 4 red". `cubes` is a slice of `input`
+pub fn parse_cubes(input: &str, cubes: &str) -> Result<Cubes, ParseError> {
+    let mut ret = Cubes::default();
+    for shown in cubes.split(',') {
+        let (count, colour) = split_once(input, shown.trim(), " ")?;
+        if colour.is_empty() || !colour.chars().all(char::is_alphabetic) {
+            return Err(ParseError::at(input, colour, "a colour"));
+        }
+        ret.add(colour, number(input, count, "a number of cubes")?);
+    }
+    Ok(ret)
+}
+
+// a line of the input, like "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
+pub fn parse_game(input: &str, line: &str) -> Result<Game, ParseError> {
+    let (header, reveals) = split_once(input, line, ": ")?;
+    let id = header.strip_prefix("Game ").ok_or_else(|| ParseError::at(input, header, "\"Game <id>\""))?;
+    let id = number(input, id, "a game id")?;
+    let reveals = reveals.split(';').map(|reveal| parse_cubes(input, reveal)).collect::<Result<_, _>>()?;
+    Ok(Game { id, reveals })
+}
+
+pub fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    input.lines().map(|line| parse_game(input, line)).collect()
+}
+
+#[test]
+fn test_1() {
+    let input = "Game 7: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\nGame 8: 2 teal, 1 red, 1 teal; 5 vermilion\n";
+    let games = parse_games(input).unwrap();
+    assert_eq!(7, games[0].id);
+    assert_eq!(Cubes::new([("blue", 6), ("green", 2), ("red", 1)]), games[0].reveals[1]);
+    assert_eq!(Cubes::new([("blue", 6), ("green", 2), ("red", 4)]), games[0].minimal_bag());
+    assert_eq!(3, games[1].reveals[0].count("teal"));
+    assert_eq!("1 red, 3 teal", games[1].reveals[0].to_string());
+    assert_eq!(0, games[1].reveals[0].count("blue"));
+    assert_eq!(vec![("red", 1), ("teal", 3), ("vermilion", 5)], games[1].minimal_bag().iter().collect::<Vec<_>>());
+
+    let e = parse_games("Game 1: 3 blue, 4 red\nGame 2: 1 blue; 2 purple!").unwrap_err();
+    assert_eq!((2, 19, "a colour"), (e.line, e.column, e.expected.as_str()));
+    let e = parse_games("Game 1: 3 blue, 4 red; 2").unwrap_err();
+    assert_eq!((1, 25, "\" \""), (e.line, e.column, e.expected.as_str()));
+}
@@ -0,0 +1,375 @@
+/*
+--- Day 20: Pulse Propagation ---
+With your help, the Elves manage to find the right parts and fix all of the machines. Now, they just need to send the command to boot up the machines and get the sand flowing again.
+
+The machines are far apart and wired together with long cables. The cables don't connect to the machines directly, but rather to communication modules attached to the machines that perform various initialization tasks and also act as communication relays.
+
+Modules communicate using pulses. Each pulse is either a high pulse or a low pulse. When a module sends a pulse, it sends that type of pulse to each module in its list of destination modules.
+
+There are several different types of modules:
+
+Flip-flop modules (prefix %) are either on or off; they are initially off. If a flip-flop module receives a high pulse, it is ignored and nothing happens. However, if a flip-flop module receives a low pulse, it flips between on and off. If it was off, it turns on and sends a high pulse. If it was on, it turns off and sends a low pulse.
+
+Conjunction modules (prefix &) remember the type of the most recent pulse received from each of their connected input modules; they initially default to remembering a low pulse for each input. When a pulse is received, the conjunction module first updates its memory for that input. Then, if it remembers high pulses for all inputs, it sends a low pulse; otherwise, it sends a high pulse.
+
+There is a single broadcast module (named broadcaster). When it receives a pulse, it sends the same pulse to all of its destination modules.
+
+Here at Desert Machine Headquarters, there is a module with a single button on it called, aptly, the button module. When you push the button, a single low pulse is sent directly to the broadcaster module.
+
+After pushing the button, you must wait until all pulses have been delivered and fully handled before pushing it again. Never push the button if modules are still processing pulses.
+
+Pulses are always processed in the order they are sent. So, if a pulse is sent to modules a, b, and c, and then module a processes its pulse and sends more pulses, the pulses sent to modules b and c would have to be handled first.
+
+The module configuration (your puzzle input) lists each module. The name of the module is preceded by a symbol identifying its type, if any. The name is then followed by an arrow and a list of its destination modules. For example:
+
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+In this module configuration, the broadcaster has three destination modules named a, b, and c. Each of these modules is a flip-flop module (as indicated by the % prefix). a outputs to b which outputs to c which outputs to another module named inv. inv is a conjunction module (as indicated by the & prefix) which, because it has only one input, acts like an inverter (it sends the opposite of the pulse type it receives); it outputs to a.
+
+By pushing the button once, the following pulses are sent:
+
+button -low-> broadcaster
+broadcaster -low-> a
+broadcaster -low-> b
+broadcaster -low-> c
+a -high-> b
+b -high-> c
+c -high-> inv
+inv -low-> a
+a -low-> b
+b -low-> c
+c -low-> inv
+inv -high-> a
+After this sequence, the flip-flop modules all end up off, so pushing the button again repeats the same sequence.
+
+Here's a more interesting example:
+
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+This module configuration includes the broadcaster, two flip-flops (named a and b), a single-input conjunction module (inv), a multi-input conjunction module (con), and an untyped module named output (for testing purposes). The multi-input conjunction module con watches the two flip-flop modules and, if they're both on, sends a low pulse to the output module.
+
+Here's what happens if you push the button once:
+
+button -low-> broadcaster
+broadcaster -low-> a
+a -high-> inv
+a -high-> con
+inv -low-> b
+con -high-> output
+b -high-> con
+con -low-> output
+Both flip-flops turn on and a low pulse is sent to output! However, now that both flip-flops are on and con remembers a high pulse from each of its two inputs, pushing the button a second time does something different:
+
+button -low-> broadcaster
+broadcaster -low-> a
+a -low-> inv
+a -low-> con
+inv -high-> b
+con -high-> output
+Flip-flop a turns off! Now, con remembers a low pulse from module a, and so it sends only a high pulse to output.
+
+Push the button a third time:
+
+button -low-> broadcaster
+broadcaster -low-> a
+a -high-> inv
+a -high-> con
+inv -low-> b
+con -low-> output
+b -low-> con
+con -high-> output
+This time, flip-flop a turns on, then flip-flop b turns off. However, before b can turn off, the pulse sent to con is handled first, so it briefly remembers all high pulses for its inputs and sends a low pulse to output. After that, flip-flop b turns off, which causes con to update its state and send a high pulse to output.
+
+Finally, with a on and b off, push the button a fourth time:
+
+button -low-> broadcaster
+broadcaster -low-> a
+a -low-> inv
+a -low-> con
+inv -high-> b
+con -high-> output
+This completes the cycle: a turns off, causing con to remember only low pulses and restoring all modules to their original states.
+
+To get the cables warmed up, the Elves have pushed the button 1000 times. How many pulses got sent as a result (including the pulses sent by the button itself)?
+
+In the first example, the same thing happens every time the button is pushed: 8 low pulses and 4 high pulses are sent. So, after pushing the button 1000 times, 8000 low pulses and 4000 high pulses are sent. Multiplying these together gives 32000000.
+
+In the second example, after pushing the button 1000 times, 4250 low pulses and 2750 high pulses are sent. Multiplying these together gives 11687500.
+
+Consult your module configuration; determine the number of low pulses and high pulses that would be sent after pushing the button 1000 times, waiting for all pulses to be fully handled after each push of the button. What do you get if you multiply the total number of low pulses sent by the total number of high pulses sent?
+
+--- Part Two ---
+The final machine responsible for moving the sand down to Island Island has a module attached named rx. The machine turns on when a single low pulse is sent to rx.
+
+Reset all modules to their default states. Waiting for all pulses to be fully handled after each button press, what is the fewest number of button presses required to deliver a single low pulse to the module named rx?
+
+
+*/
+
+use std::collections::{HashMap, HashSet};
+use aoc_core::{ParseError, Solution};
+use aoc_core::cycle::{self, Cycle};
+use aoc_core::parse::split_once;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Pulse {
+    Low,
+    High
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Module {
+    FlipFlop(bool),
+    Conjunction(HashMap<String, Pulse>),
+    Broadcast(),
+}
+
+type OutputTable = HashMap<String, Vec<String>>;
+type ModuleTable = HashMap<String, Module>;
+pub type Configuration = (OutputTable, ModuleTable);
+
+fn is_module_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+// a line like "%a -> inv, con"
+fn parse(input: &str) -> Result<Configuration, ParseError> {
+    let mut output_table  = HashMap::new();
+    let mut module_table  = HashMap::new();
+
+    for s in input.lines() {
+        let (name, outputs) = split_once(input, s, " -> ")?;
+        let (module, name) = if let Some(name) = name.strip_prefix("%") {
+            (Module::FlipFlop(false), name)
+        } else if let Some(name) = name.strip_prefix("&") {
+            (Module::Conjunction(HashMap::new()), name)
+        } else {
+            // the broadcaster, or a module that only passes pulses along
+            (Module::Broadcast(), name)
+        };
+        if !is_module_name(name) {
+            return Err(ParseError::at(input, name, "%<flip-flop>, &<conjunction> or the name of a module"));
+        }
+        module_table.insert(name.to_string(), module);
+
+        let table = output_table.entry(name.to_string()).or_insert_with(std::vec::Vec::new);
+        for o in outputs.split(", ").filter(|_| !outputs.is_empty()) {
+            if !is_module_name(o) {
+                return Err(ParseError::at(input, o, "the name of a module"));
+            }
+            table.push(o.to_string());
+        }
+    }
+
+    if !module_table.contains_key("broadcaster") {
+        return Err(ParseError::missing(input, "a broadcaster module"));
+    }
+
+     output_table.iter().for_each(|(from,v)| {
+        for to in v {
+            if let Some(Module::Conjunction(inputs)) = module_table.get_mut(to) {
+                inputs.insert(from.clone(), Pulse::Low);
+            }
+        }
+     });
+
+     Ok((output_table, module_table))
+}
+
+// the observer is told about every pulse sent while the button is pressed
+fn press_button(output_table: &OutputTable, module_table: &mut ModuleTable, observer: &mut dyn FnMut(&str, &str, Pulse)) -> (u32,u32) {
+    let (mut pulse_low, mut pulse_high) = (1,0);
+    let to = "broadcaster".to_string();
+    let mut pulses = output_table[&to].iter().map(|o| (&to, o, Pulse::Low)).collect::<Vec<(&String, &String, Pulse)>>();
+    while !pulses.is_empty() {
+        let (from, to, pulse) = pulses.remove(0);
+
+        observer(from, to, pulse);
+        match pulse {
+            Pulse::Low => pulse_low += 1,
+            Pulse::High => pulse_high += 1
+        }
+
+        if let Some(module) = module_table.get_mut(to) {
+            match (pulse, module) {
+                (Pulse::High, Module::FlipFlop(_)) => {
+                },
+                (Pulse::Low, Module::FlipFlop(b)) => {
+                    match *b {
+                        false => output_table[to].iter().for_each(|output| pulses.push((to, output, Pulse::High))),
+                        true => output_table[to].iter().for_each(|output| pulses.push((to, output, Pulse::Low)))
+                    }
+                    *b = !*b;
+                },
+                (pulse, Module::Conjunction(inputs)) => {
+                    inputs.insert(from.clone(), pulse);
+                    if inputs.iter().all(|(_,p)| *p == Pulse::High) {
+                        output_table[to].iter().for_each(|output| pulses.push((to, output, Pulse::Low)));
+                    } else {
+                        output_table[to].iter().for_each(|output| pulses.push((to, output, Pulse::High)));
+                    }
+                },
+                _ => {}
+            }
+        }
+    }
+    
+    (pulse_low, pulse_high)
+}
+
+fn puzzle_part_one(configuration: &Configuration) -> u32 {
+    let (output_table, mut module_table) = configuration.clone();
+    let sum = (0..1000).fold((0,0), |acc,_| {
+        let b = press_button(&output_table, &mut module_table, &mut |_,_,_| {});
+        (acc.0+b.0, acc.1+b.1)
+    });
+    sum.0 * sum.1
+}
+
+/* ---------- part two ---------- */
+
+// the modules `name` depends on, itself included: the others can not change what it sends
+fn upstream(output_table: &OutputTable, module_table: &ModuleTable, name: &str) -> ModuleTable {
+    let mut found = HashSet::from([name]);
+    let mut stack = vec![name];
+    while let Some(to) = stack.pop() {
+        for (from, outputs) in output_table {
+            if outputs.iter().any(|o| o == to) && found.insert(from) {
+                stack.push(from);
+            }
+        }
+    }
+    module_table.iter()
+    .filter(|(name, _)| found.contains(name.as_str()))
+    .map(|(name, module)| (name.clone(), module.clone()))
+    .collect()
+}
+
+// the flip-flops and the memories of the conjunctions, in a fixed order
+fn fingerprint(module_table: &ModuleTable) -> Vec<bool> {
+    let mut names: Vec<&String> = module_table.keys().collect();
+    names.sort();
+    names.into_iter().flat_map(|name| match &module_table[name] {
+        Module::FlipFlop(on) => vec![*on],
+        Module::Conjunction(inputs) => {
+            let mut memory: Vec<(&String, &Pulse)> = inputs.iter().collect();
+            memory.sort_by_key(|(from, _)| *from);
+            memory.into_iter().map(|(_, p)| *p == Pulse::High).collect()
+        },
+        Module::Broadcast() => vec![],
+    })
+    .collect()
+}
+
+// the counters of the puzzle loop within 4096 presses, the states of a bigger network would not
+// fit in memory
+const MAX_PRESSES: usize = 1 << 14;
+
+// the presses during which `input` sends a high pulse to the feeder, over the cycle of the
+// modules upstream of it. None when they do not loop within MAX_PRESSES
+fn high_presses(output_table: &OutputTable, module_table: &ModuleTable, input: &str, feeder: &str) -> Option<(Cycle, Vec<usize>)> {
+    let start = (upstream(output_table, module_table, input), false);
+    let (cycle, states) = cycle::hashed_within(start, |(modules, _)| {
+        let mut modules = modules.clone();
+        let mut high = false;
+        press_button(output_table, &mut modules, &mut |from, to, pulse| {
+            high |= from == input && to == feeder && pulse == Pulse::High;
+        });
+        (modules, high)
+    }, |(modules, high)| (fingerprint(modules), *high), MAX_PRESSES)?;
+
+    let presses = states.iter()
+    .enumerate()
+    .filter(|(_, (_, high))| *high)
+    .map(|(press, _)| press)
+    .collect();
+    Some((cycle, presses))
+}
+
+// rx is fed by a single conjunction that only sends a low pulse when all its inputs are high.
+// each input only depends on a small part of the network, which loops much sooner than the
+// whole: the inputs are high together when their own cycles line up.
+fn puzzle_part_two((output_table, module_table): &Configuration) -> Result<u64, ParseError> {
+    let feeder = output_table.iter()
+    .find(|(_, outputs)| outputs.iter().any(|o| o == "rx"))
+    .map(|(name, _)| name.clone())
+    .ok_or_else(|| ParseError::whole("a module that sends pulses to rx", "none"))?;
+
+    let inputs = match module_table.get(&feeder) {
+        Some(Module::Conjunction(inputs)) if !inputs.is_empty() => inputs,
+        _ => return Err(ParseError::whole("rx fed by a conjunction with inputs", format!("{:?}", feeder))),
+    };
+    let inputs: Vec<(Cycle, Vec<usize>)> = inputs.keys()
+    .map(|input| high_presses(output_table, module_table, input, &feeder))
+    .collect::<Option<_>>()
+    .ok_or_else(|| ParseError::whole(format!("inputs of {} that loop within {} presses", feeder, MAX_PRESSES), "more"))?;
+
+    cycle::align(&inputs)
+    .map(|presses| presses as u64)
+    .ok_or_else(|| ParseError::whole("a press at which the inputs of the feeder are all high", "none"))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Configuration;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Configuration, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(configuration: &Configuration) -> u32 {
+        puzzle_part_one(configuration)
+    }
+
+    fn part_two(configuration: &Configuration) -> u64 {
+        Day20::try_part_two(configuration).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    // part two only answers for a network that feeds rx like the puzzle input does
+    fn try_part_two(configuration: &Configuration) -> Result<u64, ParseError> {
+        puzzle_part_two(configuration)
+    }
+}
+
+#[test]
+fn test_1() {
+    let test_str = include_str!("../assets/example");
+    assert_eq!(32000000, Day20::part_one(&Day20::parse(test_str).unwrap()));
+}
+
+#[test]
+fn test_2() {
+    let input = Day20::parse(include_str!("../assets/test2")).unwrap();
+    assert_eq!(11687500, Day20::part_one(&input));
+}
+
+#[test]
+fn test_3() {
+    let e = Day20::parse("broadcaster -> a\n%a -> inv,con").unwrap_err();
+    assert_eq!((2, 7, "\"inv,con\""), (e.line, e.column, e.found.as_str()));
+    let e = Day20::parse("broadcaster -> a\n%a inv").unwrap_err();
+    assert_eq!((2, 7, "end of input"), (e.line, e.column, e.found.as_str()));
+}
+
+#[test]
+fn test_4() {
+    // the examples have no rx, in the last one its feeder is a flip-flop
+    let input = Day20::parse(include_str!("../assets/example")).unwrap();
+    assert_eq!("a module that sends pulses to rx", Day20::try_part_two(&input).unwrap_err().expected);
+    let input = Day20::parse("broadcaster -> a\n%a -> rx\n").unwrap();
+    assert_eq!("rx fed by a conjunction with inputs", Day20::try_part_two(&input).unwrap_err().expected);
+
+    // 15 flip-flops in a row count up to 32768 presses before starting over
+    let chain: String = (b'a'..=b'o').map(|c| format!("%{} -> {}\n", c as char, if c == b'o' { 'z' } else { (c + 1) as char })).collect();
+    let input = Day20::parse(&format!("broadcaster -> a\n{}&z -> rx\n", chain)).unwrap();
+    assert_eq!("inputs of z that loop within 16384 presses", Day20::try_part_two(&input).unwrap_err().expected);
+}
@@ -0,0 +1,333 @@
+/*
+--- Day 5: If You Give A Seed A Fertilizer ---
+You take the boat and find the gardener right where you were told he would be: managing a giant "garden" that looks more to you like a farm.
+
+"A water source? Island Island is the water source!" You point out that Snow Island isn't receiving any water.
+
+"Oh, we had to stop the water because we ran out of sand to filter it with! Can't make snow with dirty water. Don't worry, I'm sure we'll get more sand soon; we only turned off the water a few days... weeks... oh no." His face sinks into a look of horrified realization.
+
+"I've been so busy making sure everyone here has food that I completely forgot to check why we stopped getting more sand! There's a ferry leaving soon that is headed over in that direction - it's much faster than your boat. Could you please go check it out?"
+
+You barely have time to agree to this request when he brings up another. "While you wait for the ferry, maybe you can help us with our food production problem. The latest Island Island Almanac just arrived and we're having trouble making sense of it."
+
+The almanac (your puzzle input) lists all of the seeds that need to be planted. It also lists what type of soil to use with each kind of seed, what type of fertilizer to use with each kind of soil, what type of water to use with each kind of fertilizer, and so on. Every type of seed, soil, fertilizer and so on is identified with a number, but numbers are reused by each category - that is, soil 123 and fertilizer 123 aren't necessarily related to each other.
+
+For example:
+
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+The almanac starts by listing which seeds need to be planted: seeds 79, 14, 55, and 13.
+
+The rest of the almanac contains a list of maps which describe how to convert numbers from a source category into numbers in a destination category. That is, the section that starts with seed-to-soil map: describes how to convert a seed number (the source) to a soil number (the destination). This lets the gardener and his team know which soil to use with which seeds, which water to use with which fertilizer, and so on.
+
+Rather than list every source number and its corresponding destination number one by one, the maps describe entire ranges of numbers that can be converted. Each line within a map contains three numbers: the destination range start, the source range start, and the range length.
+
+Consider again the example seed-to-soil map:
+
+50 98 2
+52 50 48
+The first line has a destination range start of 50, a source range start of 98, and a range length of 2. This line means that the source range starts at 98 and contains two values: 98 and 99. The destination range is the same length, but it starts at 50, so its two values are 50 and 51. With this information, you know that seed number 98 corresponds to soil number 50 and that seed number 99 corresponds to soil number 51.
+
+The second line means that the source range starts at 50 and contains 48 values: 50, 51, ..., 96, 97. This corresponds to a destination range starting at 52 and also containing 48 values: 52, 53, ..., 98, 99. So, seed number 53 corresponds to soil number 55.
+
+Any source numbers that aren't mapped correspond to the same destination number. So, seed number 10 corresponds to soil number 10.
+
+So, the entire list of seed numbers and their corresponding soil numbers looks like this:
+
+seed  soil
+0     0
+1     1
+...   ...
+48    48
+49    49
+50    52
+51    53
+...   ...
+96    98
+97    99
+98    50
+99    51
+With this map, you can look up the soil number required for each initial seed number:
+
+Seed number 79 corresponds to soil number 81.
+Seed number 14 corresponds to soil number 14.
+Seed number 55 corresponds to soil number 57.
+Seed number 13 corresponds to soil number 13.
+The gardener and his team want to get started as soon as possible, so they'd like to know the closest location that needs a seed. Using these maps, find the lowest location number that corresponds to any of the initial seeds. To do this, you'll need to convert each seed number through other categories until you can find its corresponding location number. In this example, the corresponding types are:
+
+Seed 79, soil 81, fertilizer 81, water 81, light 74, temperature 78, humidity 78, location 82.
+Seed 14, soil 14, fertilizer 53, water 49, light 42, temperature 42, humidity 43, location 43.
+Seed 55, soil 57, fertilizer 57, water 53, light 46, temperature 82, humidity 82, location 86.
+Seed 13, soil 13, fertilizer 52, water 41, light 34, temperature 34, humidity 35, location 35.
+So, the lowest location number in this example is 35.
+
+What is the lowest location number that corresponds to any of the initial seed numbers?
+
+--- Part Two ---
+Everyone will starve if you only plant such a small number of seeds. Re-reading the almanac, it looks like the seeds: line actually describes ranges of seed numbers.
+
+The values on the initial seeds: line come in pairs. Within each pair, the first value is the start of the range and the second value is the length of the range. So, in the first line of the example above:
+
+seeds: 79 14 55 13
+This line describes two ranges of seed numbers to be planted in the garden. The first range starts with seed number 79 and contains 14 values: 79, 80, ..., 91, 92. The second range starts with seed number 55 and contains 13 values: 55, 56, ..., 66, 67.
+
+Now, rather than considering four seed numbers, you need to consider a total of 27 seed numbers.
+
+In the above example, the lowest location number can be obtained from seed number 82, which corresponds to soil 84, fertilizer 84, water 84, light 77, temperature 45, humidity 46, and location 46. So, the lowest location number is 46.
+
+Consider all of the initial seed numbers listed in the ranges on the first line of the almanac. What is the lowest location number that corresponds to any of the initial seed numbers?
+
+*/
+
+use aoc_core::{ParseError, Solution};
+use aoc_core::parse::number;
+use std::ops::Range;
+
+// a simple range with lookup function
+
+#[derive(Debug)]
+pub struct RangeMap {
+   start_dst: u64,
+   start_src: u64,
+   range_sze: u64,
+}
+
+impl RangeMap {
+   fn lookup(&self, src: u64) -> Option<u64> {
+      if src < self.start_src {
+         return None
+      }
+      let i = src - self.start_src;
+      if i >= self.range_sze {
+         None 
+      } else {
+         Some(self.start_dst + i)
+      }
+   }
+}
+
+
+// when we have multiple ranges, either one of them is a match or we return same number
+
+#[derive(Debug)]
+pub struct DestinationMap {
+   ranges: Vec<RangeMap>
+}
+
+impl DestinationMap {
+   fn lookup(&self, src: u64) -> u64 {
+       for r in &self.ranges {
+         match r.lookup(src) {
+            Some(v) => return v,
+            None => continue
+         }
+       }
+       src
+   }
+}
+
+// Almanach is the parsed input
+
+#[derive(Debug)]
+pub struct Almanach {
+   seeds: Vec<u64>,
+   maps: Vec<DestinationMap>,
+}  
+
+// the numbers of the almanach fit in 32 bits, a range past them still fits in 64
+fn parse_numbers(input: &str, line: &str) -> Result<Vec<u64>, ParseError> {
+   line.split_whitespace().map(|n| number::<u32>(input, n, "a number of 32 bits").map(u64::from)).collect()
+}
+
+fn load_almanach(input: &str) -> Result<Almanach, ParseError> {
+   let mut lines = input.lines();
+   let seeds = match lines.next() {
+      Some(line) => line.strip_prefix("seeds:").ok_or_else(|| ParseError::at(input, line, "\"seeds: <numbers>\""))?,
+      None => return Err(ParseError::missing(input, "\"seeds: <numbers>\"")),
+   };
+   let mut ret: Almanach = Almanach { 
+      seeds: parse_numbers(input, seeds)?, 
+      maps: Vec::new(),
+   };
+
+   for line in lines.filter(|l| !l.is_empty()) {
+      if line.ends_with(" map:") {
+         ret.maps.push(
+            DestinationMap {
+               ranges: Vec::new()
+            });
+         continue;
+      }
+      let Some(map) = ret.maps.last_mut() else {
+         return Err(ParseError::at(input, line, "\"<source>-to-<destination> map:\""));
+      };
+      let mapping = parse_numbers(input, line)?;
+      if mapping.len() != 3 {
+         return Err(ParseError::at(input, line, "a destination start, a source start and a length"));
+      }
+      map.ranges.push(RangeMap{
+         start_dst: mapping[0],
+         start_src: mapping[1],
+         range_sze: mapping[2],
+      });
+   }
+   Ok(ret)
+}
+
+fn seed_to_location(seed: u64, almanach: &Almanach) -> u64 {
+   let mut i: u64 = seed;
+   for m in &almanach.maps {
+      i = m.lookup(i)
+   }  
+   i
+}
+
+fn solve_puzzle_part_one(almanach: &Almanach) -> u64 {
+   let mut lowest = u64::MAX;
+   for seed in &almanach.seeds {
+      let i = seed_to_location(*seed, almanach);
+      if i < lowest {
+         lowest = i;
+      }
+   }
+   lowest
+}
+
+/* ------------ part two ------------ */
+
+/* 
+   1809081164 seeds to try, each taking 6 lookups  
+
+   map 0 => total range is 4114855217 max is 662865138
+   map 1 => total range is 4270604933 max is 571426669
+   map 2 => total range is 3993472324 max is 286820892
+   map 3 => total range is 4099135953 max is 815715100
+   map 4 => total range is 3898760701 max is 587176321
+   map 5 => total range is 3859118751 max is 492194617
+   map 6 => total range is 4279575168 max is 382426498
+
+   so we are better off trying all seeds rather than trying all locations (also location space is unbounded).
+
+   that took a minute, and a single range of 4 billion seeds took as long as the whole input. the
+   ranges now go through each map as a whole, cut where the ranges of the map start and end.
+*/
+
+// the ranges `map` sends `ranges` to. the part of a range covered by a range of the map is moved,
+// what is left is looked up again in the other ranges of the map, or kept where it is
+fn map_ranges(map: &DestinationMap, mut ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+   let mut ret = Vec::new();
+   while let Some(r) = ranges.pop() {
+      let src = |m: &RangeMap| m.start_src..m.start_src + m.range_sze;
+      match map.ranges.iter().find(|m| src(m).start < r.end && r.start < src(m).end) {
+         Some(m) => {
+            let (start, end) = (r.start.max(m.start_src), r.end.min(src(m).end));
+            ret.push(m.start_dst + (start - m.start_src)..m.start_dst + (end - m.start_src));
+            if r.start < start {
+               ranges.push(r.start..start);
+            }
+            if end < r.end {
+               ranges.push(end..r.end);
+            }
+         },
+         None => ret.push(r),
+      }
+   }
+   ret
+}
+
+fn solve_puzzle_part_two(almanach: &Almanach) -> Result<u64, ParseError> {
+   if !almanach.seeds.len().is_multiple_of(2) {
+      return Err(ParseError::whole("seeds in pairs of a start and a length", format!("{} numbers", almanach.seeds.len())));
+   }
+   let ranges: Vec<Range<u64>> = almanach.seeds.chunks(2)
+   .map(|pair| pair[0]..pair[0] + pair[1])
+   .filter(|r| !r.is_empty())
+   .collect();
+
+   almanach.maps.iter()
+   .fold(ranges, |ranges, map| map_ranges(map, ranges))
+   .into_iter()
+   .map(|r| r.start)
+   .min()
+   .ok_or_else(|| ParseError::whole("a range with seeds", "none"))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+   type Input = Almanach;
+   type Answer1 = u64;
+   type Answer2 = u64;
+
+   fn parse(input: &str) -> Result<Almanach, ParseError> {
+      load_almanach(input)
+   }
+
+   fn part_one(almanach: &Almanach) -> u64 {
+      solve_puzzle_part_one(almanach)
+   }
+
+   fn part_two(almanach: &Almanach) -> u64 {
+      Day5::try_part_two(almanach).unwrap_or_else(|e| panic!("{}", e))
+   }
+
+   // the seeds of part two come in pairs, and some of them must be planted
+   fn try_part_two(almanach: &Almanach) -> Result<u64, ParseError> {
+      solve_puzzle_part_two(almanach)
+   }
+}
+
+#[test]
+fn test_1() {
+   let test_str = include_str!("../assets/example");
+   let input = Day5::parse(test_str).unwrap();
+   assert_eq!(35, Day5::part_one(&input));
+   assert_eq!(46, Day5::part_two(&input));
+}
+
+#[test]
+fn test_2() {
+   // 4 billion seeds, cut by a map in three
+   let input = Day5::parse("seeds: 0 4294967295\n\na-to-b map:\n10 0 5\n0 5 10\n").unwrap();
+   assert_eq!(10, Day5::part_one(&input));
+   assert_eq!(0, Day5::part_two(&input));
+   let input = Day5::parse("seeds: 3 2 1 0\n\na-to-b map:\n10 0 5\n0 5 10\n").unwrap();
+   assert_eq!((10, 13), (Day5::part_one(&input), Day5::part_two(&input)));
+
+   assert!(Day5::parse("seeds: 4294967296\n").is_err());
+   let input = Day5::parse("seeds: 1 0 2\n").unwrap();
+   assert_eq!("seeds in pairs of a start and a length", Day5::try_part_two(&input).unwrap_err().expected);
+   let input = Day5::parse("seeds: 1 0\n").unwrap();
+   assert_eq!("a range with seeds", Day5::try_part_two(&input).unwrap_err().expected);
+}
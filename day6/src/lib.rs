@@ -0,0 +1,218 @@
+/*
+--- Day 6: Wait For It ---
+The ferry quickly brings you across Island Island. After asking around, you discover that there is indeed normally a large pile of sand somewhere near here, but you don't see anything besides lots of water and the small island where the ferry has docked.
+
+As you try to figure out what to do next, you notice a poster on a wall near the ferry dock. "Boat races! Open to the public! Grand prize is an all-expenses-paid trip to Desert Island!" That must be where the sand comes from! Best of all, the boat races are starting in just a few minutes.
+
+You manage to sign up as a competitor in the boat races just in time. The organizer explains that it's not really a traditional race - instead, you will get a fixed amount of time during which your boat has to travel as far as it can, and you win if your boat goes the farthest.
+
+As part of signing up, you get a sheet of paper (your puzzle input) that lists the time allowed for each race and also the best distance ever recorded in that race. To guarantee you win the grand prize, you need to make sure you go farther in each race than the current record holder.
+
+The organizer brings you over to the area where the boat races are held. The boats are much smaller than you expected - they're actually toy boats, each with a big button on top. Holding down the button charges the boat, and releasing the button allows the boat to move. Boats move faster if their button was held longer, but time spent holding the button counts against the total race time. You can only hold the button at the start of the race, and boats don't move until the button is released.
+
+For example:
+
+Time:      7  15   30
+Distance:  9  40  200
+This document describes three races:
+
+The first race lasts 7 milliseconds. The record distance in this race is 9 millimeters.
+The second race lasts 15 milliseconds. The record distance in this race is 40 millimeters.
+The third race lasts 30 milliseconds. The record distance in this race is 200 millimeters.
+Your toy boat has a starting speed of zero millimeters per millisecond. For each whole millisecond you spend at the beginning of the race holding down the button, the boat's speed increases by one millimeter per millisecond.
+
+So, because the first race lasts 7 milliseconds, you only have a few options:
+
+Don't hold the button at all (that is, hold it for 0 milliseconds) at the start of the race. The boat won't move; it will have traveled 0 millimeters by the end of the race.
+Hold the button for 1 millisecond at the start of the race. Then, the boat will travel at a speed of 1 millimeter per millisecond for 6 milliseconds, reaching a total distance traveled of 6 millimeters.
+Hold the button for 2 milliseconds, giving the boat a speed of 2 millimeters per millisecond. It will then get 5 milliseconds to move, reaching a total distance of 10 millimeters.
+Hold the button for 3 milliseconds. After its remaining 4 milliseconds of travel time, the boat will have gone 12 millimeters.
+Hold the button for 4 milliseconds. After its remaining 3 milliseconds of travel time, the boat will have gone 12 millimeters.
+Hold the button for 5 milliseconds, causing the boat to travel a total of 10 millimeters.
+Hold the button for 6 milliseconds, causing the boat to travel a total of 6 millimeters.
+Hold the button for 7 milliseconds. That's the entire duration of the race. You never let go of the button. The boat can't move until you let go of the button. Please make sure you let go of the button so the boat gets to move. 0 millimeters.
+Since the current record for this race is 9 millimeters, there are actually 4 different ways you could win: you could hold the button for 2, 3, 4, or 5 milliseconds at the start of the race.
+
+In the second race, you could hold the button for at least 4 milliseconds and at most 11 milliseconds and beat the record, a total of 8 different ways to win.
+
+In the third race, you could hold the button for at least 11 milliseconds and no more than 19 milliseconds and still beat the record, a total of 9 ways you could win.
+
+To see how much margin of error you have, determine the number of ways you can beat the record in each race; in this example, if you multiply these values together, you get 288 (4 * 8 * 9).
+
+Determine the number of ways you could beat the record in each race. What do you get if you multiply these numbers together?
+
+Puzzle Input:
+Time:        61     67     75     71
+Distance:   430   1036   1307   1150
+*/
+
+use aoc_core::{ParseError, Solution};
+use aoc_core::parse::number;
+
+type Race = (i64,i64);
+pub type Races = Vec<Race>;
+
+// a line like "Time:      7  15   30"
+fn numbers(input: &str, line: Option<&str>, label: &str) -> Result<Vec<i64>, ParseError> {
+   let expected = format!("\"{} <numbers>\"", label);
+   let line = line.ok_or_else(|| ParseError::missing(input, &expected))?;
+   let numbers = line.strip_prefix(label).ok_or_else(|| ParseError::at(input, line, &expected))?;
+   let numbers: Vec<i64> = numbers.split_whitespace().map(|n| number::<u32>(input, n, "a number").map(i64::from)).collect::<Result<_, _>>()?;
+   // part two joins the numbers of every race, they must make a number still
+   let joined = numbers.iter().map(|n| n.to_string()).collect::<String>();
+   match joined.parse::<i64>() {
+      Ok(_) => Ok(numbers),
+      Err(_) if numbers.is_empty() => Err(ParseError::at(input, &line[line.len()..], "a number")),
+      Err(_) => Err(ParseError::at(input, line, "numbers that still fit in 64 bits once joined")),
+   }
+}
+
+fn load_race(input: &str) -> Result<Races, ParseError> {
+   let mut lines = input.lines();
+   let times = numbers(input, lines.next(), "Time:")?;
+   let distances = numbers(input, lines.next(), "Distance:")?;
+   if times.len() != distances.len() {
+      return Err(ParseError::at(input, input.lines().nth(1).unwrap(), format!("{} distances, one per race", times.len())));
+   }
+   Ok(times.into_iter().zip(distances).collect())
+}
+
+/*
+ * given x = time to hold the button down
+ * and   y = total time
+ * you get (y-x) * x as distance travelled
+ * 
+ * given z a record distance we want
+ * (y-x) * x >= z+1
+ *  y*x - x*x >= z+1
+ * -1.x^2 + y.x - (z+1) > 0 
+ * 
+ * (-1,y,-z-1) are the coefficient of our quadratic polynomial
+ * we know that the root of a quadratic polynomial are:
+ *  D = b2 - 4ac
+ *  x1 = (-b + √ D )/2a
+ *  x2 = (-b - √ D )/2a
+ * 
+ *  so it becomes:
+ *  (x-x1)(x-x2) > 0
+ */
+
+// the squares of the joined numbers of part two do not fit in an i64
+fn discriminant(a: i128,b: i128,c: i128) -> i128 {
+   b*b - 4*a*c
+}
+
+// None when there is no real root, the record cannot be beaten
+fn roots(a: i128,b: i128,c: i128) -> Option<(i128,i128)> {
+   let d =discriminant(a, b, c); 
+   if d < 0 {
+      return None;
+   }
+   Some(((-b+(f64::floor(f64::sqrt(d as f64)) as i128))/2*a,(-b-(f64::floor(f64::sqrt(d as f64)) as i128))/2*a))
+}
+
+fn distance(x: i128, y:i128) -> i128 {
+   (y-x)*x
+}
+
+fn puzzle_part_one(races: &Races) -> i64 {
+   races
+   .iter()
+   .map(|(y,z)| {
+      let y = *y as i128;
+      let z = *z as i128;
+
+      let a = -1;
+      let b = y;
+      let c = -z-1;
+
+      let Some((r1,r2)) = roots(a,b,c) else {
+         return 0;
+      };
+      let d1 = distance(r1,b);
+      
+      let ways = if d1 <= z {
+         r2-r1
+      } else {
+         r2-r1+1
+      };
+      ways.max(0) as i64
+   })
+   .product()
+}
+
+/* ------------- part two ------------- */
+
+
+// the spaces were just bad kerning, all the numbers make a single race
+fn load_race_part_two(races: &Races) -> Races {
+   let join = |v: Vec<String>| v.concat().parse().expect("the joined numbers were checked when parsing");
+   let time = join(races.iter().map(|(y,_)| y.to_string()).collect());
+   let distance = join(races.iter().map(|(_,z)| z.to_string()).collect());
+   vec![(time, distance)]
+}
+
+fn puzzle_part_two(races: &Races) -> i64 {
+   races
+   .iter()
+   .map(|(y,z)| {
+      let y = *y as i128;
+      let z = *z as i128;
+
+      let a = -1;
+      let b = y;
+      let c = -z-1;
+
+      let Some((r1,r2)) = roots(a,b,c) else {
+         return 0;
+      };
+      let d1 = distance(r1,b);
+      
+      let ways = if d1 <= z {
+         r2-r1
+      } else {
+         r2-r1+1
+      };
+      ways.max(0) as i64
+   })
+   .sum()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+   type Input = Races;
+   type Answer1 = i64;
+   type Answer2 = i64;
+
+   fn parse(input: &str) -> Result<Races, ParseError> {
+      load_race(input)
+   }
+
+   fn part_one(races: &Races) -> i64 {
+      puzzle_part_one(races)
+   }
+
+   fn part_two(races: &Races) -> i64 {
+      puzzle_part_two(&load_race_part_two(races))
+   }
+}
+
+#[test]
+fn test_1() {
+   let test_str = include_str!("../assets/example");
+   let input = Day6::parse(test_str).unwrap();
+   assert_eq!(288, Day6::part_one(&input));
+   assert_eq!(71503, Day6::part_two(&input));
+}
+
+#[test]
+fn test_2() {
+   // a record that cannot be beaten leaves no way to win
+   let input = Day6::parse("Time:      45   51   36   78\nDistance: 402  545  171 8841\n").unwrap();
+   assert_eq!(0, Day6::part_one(&input));
+   let e = Day6::parse("Time: 4000000000 4000000000 4000000000\nDistance: 1 2 3\n").unwrap_err();
+   assert_eq!((1, 1, "numbers that still fit in 64 bits once joined"), (e.line, e.column, e.expected.as_str()));
+   assert!(Day6::parse("Time: -7\nDistance: 9\n").is_err());
+   assert!(Day6::parse("Time:\nDistance:\n").is_err());
+}
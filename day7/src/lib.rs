@@ -0,0 +1,314 @@
+/*
+--- Day 7: Camel Cards ---
+Your all-expenses-paid trip turns out to be a one-way, five-minute ride in an airship. (At least it's a cool airship!) It drops you off at the edge of a vast desert and descends back to Island Island.
+
+"Did you bring the parts?"
+
+You turn around to see an Elf completely covered in white clothing, wearing goggles, and riding a large camel.
+
+"Did you bring the parts?" she asks again, louder this time. You aren't sure what parts she's looking for; you're here to figure out why the sand stopped.
+
+"The parts! For the sand, yes! Come with me; I will show you." She beckons you onto the camel.
+
+After riding a bit across the sands of Desert Island, you can see what look like very large rocks covering half of the horizon. The Elf explains that the rocks are all along the part of Desert Island that is directly above Island Island, making it hard to even get there. Normally, they use big machines to move the rocks and filter the sand, but the machines have broken down because Desert Island recently stopped receiving the parts they need to fix the machines.
+
+You've already assumed it'll be your job to figure out why the parts stopped when she asks if you can help. You agree automatically.
+
+Because the journey will take a few days, she offers to teach you the game of Camel Cards. Camel Cards is sort of similar to poker except it's designed to be easier to play while riding a camel.
+
+In Camel Cards, you get a list of hands, and your goal is to order them based on the strength of each hand. A hand consists of five cards labeled one of A, K, Q, J, T, 9, 8, 7, 6, 5, 4, 3, or 2. The relative strength of each card follows this order, where A is the highest and 2 is the lowest.
+
+Every hand is exactly one type. From strongest to weakest, they are:
+
+Five of a kind, where all five cards have the same label: AAAAA
+Four of a kind, where four cards have the same label and one card has a different label: AA8AA
+Full house, where three cards have the same label, and the remaining two cards share a different label: 23332
+Three of a kind, where three cards have the same label, and the remaining two cards are each different from any other card in the hand: TTT98
+Two pair, where two cards share one label, two other cards share a second label, and the remaining card has a third label: 23432
+One pair, where two cards share one label, and the other three cards have a different label from the pair and each other: A23A4
+High card, where all cards' labels are distinct: 23456
+Hands are primarily ordered based on type; for example, every full house is stronger than any three of a kind.
+
+If two hands have the same type, a second ordering rule takes effect. Start by comparing the first card in each hand. If these cards are different, the hand with the stronger first card is considered stronger. If the first card in each hand have the same label, however, then move on to considering the second card in each hand. If they differ, the hand with the higher second card wins; otherwise, continue with the third card in each hand, then the fourth, then the fifth.
+
+So, 33332 and 2AAAA are both four of a kind hands, but 33332 is stronger because its first card is stronger. Similarly, 77888 and 77788 are both a full house, but 77888 is stronger because its third card is stronger (and both hands have the same first and second card).
+
+To play Camel Cards, you are given a list of hands and their corresponding bid (your puzzle input). For example:
+
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+This example shows five hands; each hand is followed by its bid amount. Each hand wins an amount equal to its bid multiplied by its rank, where the weakest hand gets rank 1, the second-weakest hand gets rank 2, and so on up to the strongest hand. Because there are five hands in this example, the strongest hand will have rank 5 and its bid will be multiplied by 5.
+
+So, the first step is to put the hands in order of strength:
+
+32T3K is the only one pair and the other hands are all a stronger type, so it gets rank 1.
+KK677 and KTJJT are both two pair. Their first cards both have the same label, but the second card of KK677 is stronger (K vs T), so KTJJT gets rank 2 and KK677 gets rank 3.
+T55J5 and QQQJA are both three of a kind. QQQJA has a stronger first card, so it gets rank 5 and T55J5 gets rank 4.
+Now, you can determine the total winnings of this set of hands by adding up the result of multiplying each hand's bid with its rank (765 * 1 + 220 * 2 + 28 * 3 + 684 * 4 + 483 * 5). So the total winnings in this example are 6440.
+
+Find the rank of every hand in your set. What are the total winnings?
+
+
+--- Part Two ---
+To make things a little more interesting, the Elf introduces one additional rule. Now, J cards are jokers - wildcards that can act like whatever card would make the hand the strongest type possible.
+
+To balance this, J cards are now the weakest individual cards, weaker even than 2. The other cards stay in the same order: A, K, Q, T, 9, 8, 7, 6, 5, 4, 3, 2, J.
+
+J cards can pretend to be whatever card is best for the purpose of determining hand type; for example, QJJQ2 is now considered four of a kind. However, for the purpose of breaking ties between two hands of the same type, J is always treated as J, not the card it's pretending to be: JKKK2 is weaker than QQQQ2 because J is weaker than Q.
+
+Now, the above example goes very differently:
+
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+32T3K is still the only one pair; it doesn't contain any jokers, so its strength doesn't increase.
+KK677 is now the only two pair, making it the second-weakest hand.
+T55J5, KTJJT, and QQQJA are now all four of a kind! T55J5 gets rank 3, QQQJA gets rank 4, and KTJJT gets rank 5.
+With the new joker rule, the total winnings in this example are 5905.
+
+Using the new joker rule, find the rank of every hand in your set. What are the new total winnings?
+*/
+
+use aoc_core::{ParseError, Solution};
+use aoc_core::parse::{number, split_once};
+
+// J is a regular card in part one and a wildcard in part two
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Rules {
+   Standard,
+   Jokers,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Card {
+   As,
+   King,
+   Queen,
+   Joker,
+   Trump,
+   Nine,
+   Eight,
+   Seven,
+   Six,
+   Five,
+   Four,
+   Three,
+   Two
+}
+
+impl Card {
+   fn value(&self, rules: Rules) -> usize {
+      match self {
+         Card::As => 14,
+         Card::King=> 13,
+         Card::Queen=> 12,
+         Card::Joker if rules == Rules::Standard => 11,
+         Card::Joker => 1,
+         Card::Trump =>10,
+         Card::Nine=> 9,
+         Card::Eight=> 8,
+         Card::Seven=> 7,
+         Card::Six=> 6,
+         Card::Five=> 5,
+         Card::Four=> 4,
+         Card::Three=> 3,
+         Card::Two=> 2,
+      }
+   }
+}
+
+impl TryFrom<char> for Card {
+   type Error = char;
+
+   fn try_from(s: char) -> Result<Self, char> {
+       Ok(match s {
+           'A' => Card::As,
+           'K' => Card::King,
+           'Q' => Card::Queen,
+           'J' => Card::Joker,
+           'T' => Card::Trump,
+           '9' => Card::Nine,
+           '8' => Card::Eight,
+           '7' => Card::Seven,
+           '6' => Card::Six,
+           '5' => Card::Five,
+           '4' => Card::Four,
+           '3' => Card::Three,
+           '2' => Card::Two,      
+           _ => return Err(s),
+       })
+   }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Hand ([Card;5]);
+
+fn parse_hand(input: &str, h: &str) -> Result<Hand, ParseError> {
+   if h.chars().count() != 5 {
+      return Err(ParseError::at(input, h, "a hand of 5 cards"));
+   }
+   
+   let mut ret = [Card::Two;5];
+   for (i,(j,c)) in h.char_indices().enumerate() {
+      ret[i] = Card::try_from(c).map_err(|_| ParseError::at(input, &h[j..j+c.len_utf8()], "a card (A, K, Q, J, T or 2-9)"))?;
+   }
+
+   Ok(Hand(ret))
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum HandType {
+   FiveOak,
+   FourOak,
+   FullHouse,
+   ThreeOak,
+   TwoPair,
+   Pair,
+   High
+}
+
+impl Hand {
+   fn hand_type(&self, rules: Rules) -> HandType {
+      let mut counter = [(Card::Two, 0);15];
+      for c in self.0 {
+         counter[c.value(rules)].0 = c;
+         counter[c.value(rules)].1 += 1;
+      }
+
+      counter.sort_by_key(|c| std::cmp::Reverse(c.1));
+      let counter: Vec<(Card,i32)> = counter.into_iter().filter(|x| x.1 != 0).collect();
+      
+      let is_wildcard = |c: Card| rules == Rules::Jokers && c == Card::Joker;
+      let jokers = counter.iter().find(|&x| is_wildcard(x.0)).map_or(0, |&x| x.1);
+      let mut counter_without_joker: Vec<i32> = counter.into_iter().filter(|x| !is_wildcard(x.0)).map(|x| x.1 ).collect();
+
+      if counter_without_joker.is_empty() {
+         // five joker
+         return HandType::FiveOak;
+      } else {
+         // we distribute the jokers to the highest card
+         counter_without_joker[0] += jokers; 
+      }
+
+      if counter_without_joker[0] == 5 {
+         return HandType::FiveOak;
+      }
+
+      if counter_without_joker[0] == 4 {
+         return HandType::FourOak;
+      }
+
+      if counter_without_joker[0] == 3 && counter_without_joker[1] == 2 {
+         return HandType::FullHouse;
+      }
+      
+
+      if counter_without_joker[0] == 3 {
+         return HandType::ThreeOak;
+      }
+
+      if counter_without_joker[0] == 2 && counter_without_joker[1] == 2 {
+         return HandType::TwoPair;
+      }
+
+      if counter_without_joker[0] == 2 {
+         return HandType::Pair;
+      }
+
+      HandType::High
+   }
+
+   // Less means stronger, like HandType
+   fn compare(&self, other: &Self, rules: Rules) -> std::cmp::Ordering {
+       let self_value = self.hand_type(rules);
+       let other_value = other.hand_type(rules);
+
+       match self_value.cmp(&other_value) {
+         std::cmp::Ordering::Less => std::cmp::Ordering::Less,
+         std::cmp::Ordering::Greater => std::cmp::Ordering::Greater,
+         std::cmp::Ordering::Equal => {
+            for i in 0..5 {
+               let (s, o) = (self.0[i].value(rules), other.0[i].value(rules));
+               if s < o {
+                  return std::cmp::Ordering::Greater;
+               }
+               if o < s {
+                  return std::cmp::Ordering::Less;
+               }
+            }
+            std::cmp::Ordering::Equal
+         }
+       } 
+   }
+}
+
+
+#[derive(Debug)]
+pub struct Draw {
+   hand: Hand,
+   bid: usize,
+}
+
+pub type Bids = Vec<Draw>;
+
+fn load_bids(input: &str) -> Result<Bids, ParseError> {
+   let mut ret: Bids = Vec::new();
+
+   for line in input.lines() {
+      let (hand, bid) = split_once(input, line, " ")?;
+      let d = Draw { 
+         hand: parse_hand(input, hand)?,
+         bid: number(input, bid, "a bid")?
+      };
+      ret.push(d)
+   }
+   Ok(ret)
+}
+
+fn total_winnings(bids: &Bids, rules: Rules) -> usize {
+   let mut ranked: Vec<&Draw> = bids.iter().collect();
+   ranked.sort_by(|d1,d2| d2.hand.compare(&d1.hand, rules));
+   ranked.iter().enumerate()
+   .fold(0, |acc,(i,d)| acc + (i+1)*d.bid)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+   type Input = Bids;
+   type Answer1 = usize;
+   type Answer2 = usize;
+
+   fn parse(input: &str) -> Result<Bids, ParseError> {
+      load_bids(input)
+   }
+
+   fn part_one(bids: &Bids) -> usize {
+      total_winnings(bids, Rules::Standard)
+   }
+
+   fn part_two(bids: &Bids) -> usize {
+      total_winnings(bids, Rules::Jokers)
+   }
+}
+
+#[test]
+fn test_1() {
+   let test_str = include_str!("../assets/example");
+   let input = Day7::parse(test_str).unwrap();
+   assert_eq!(6440, Day7::part_one(&input));
+   assert_eq!(5905, Day7::part_two(&input));
+}
+
+#[test]
+fn test_2() {
+   let e = Day7::parse("32T3K 765\nT55J5 684\nKK677").unwrap_err();
+   assert_eq!((3, 6, "end of input"), (e.line, e.column, e.found.as_str()));
+   let e = Day7::parse("32T3K 765\nT5XJ5 684").unwrap_err();
+   assert_eq!((2, 3, "\"X\""), (e.line, e.column, e.found.as_str()));
+}
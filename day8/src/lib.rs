@@ -0,0 +1,261 @@
+/*
+
+--- Day 8: Haunted Wasteland ---
+You're still riding a camel across Desert Island when you spot a sandstorm quickly approaching. When you turn to warn the Elf, she disappears before your eyes! To be fair, she had just finished warning you about ghosts a few minutes ago.
+
+One of the camel's pouches is labeled "maps" - sure enough, it's full of documents (your puzzle input) about how to navigate the desert. At least, you're pretty sure that's what they are; one of the documents contains a list of left/right instructions, and the rest of the documents seem to describe some kind of network of labeled nodes.
+
+It seems like you're meant to use the left/right instructions to navigate the network. Perhaps if you have the camel follow the same instructions, you can escape the haunted wasteland!
+
+After examining the maps for a bit, two nodes stick out: AAA and ZZZ. You feel like AAA is where you are now, and you have to follow the left/right instructions until you reach ZZZ.
+
+This format defines each node of the network individually. For example:
+
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+Starting with AAA, you need to look up the next element based on the next left/right instruction in your input. In this example, start with AAA and go right (R) by choosing the right element of AAA, CCC. Then, L means to choose the left element of CCC, ZZZ. By following the left/right instructions, you reach ZZZ in 2 steps.
+
+Of course, you might not find ZZZ right away. If you run out of left/right instructions, repeat the whole sequence of instructions as necessary: RL really means RLRLRLRLRLRLRLRL... and so on. For example, here is a situation that takes 6 steps to reach ZZZ:
+
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+Starting at AAA, follow the left/right instructions. How many steps are required to reach ZZZ?
+
+Your puzzle answer was 12599.
+
+The first half of this puzzle is complete! It provides one gold star: *
+
+--- Part Two ---
+The sandstorm is upon you and you aren't any closer to escaping the wasteland. You had the camel follow the instructions, but you've barely left your starting position. It's going to take significantly more steps to escape!
+
+What if the map isn't for people - what if the map is for ghosts? Are ghosts even bound by the laws of spacetime? Only one way to find out.
+
+After examining the maps a bit longer, your attention is drawn to a curious fact: the number of nodes with names ending in A is equal to the number ending in Z! If you were a ghost, you'd probably just start at every node that ends with A and follow all of the paths at the same time until they all simultaneously end up at nodes that end with Z.
+
+For example:
+
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+Here, there are two starting nodes, 11A and 22A (because they both end with A). As you follow each left/right instruction, use that instruction to simultaneously navigate away from both nodes you're currently on. Repeat this process until all of the nodes you're currently on end with Z. (If only some of the nodes you're on end with Z, they act like any other node and you continue as normal.) In this example, you would proceed as follows:
+
+Step 0: You are at 11A and 22A.
+Step 1: You choose all of the left paths, leading you to 11B and 22B.
+Step 2: You choose all of the right paths, leading you to 11Z and 22C.
+Step 3: You choose all of the left paths, leading you to 11B and 22Z.
+Step 4: You choose all of the right paths, leading you to 11Z and 22B.
+Step 5: You choose all of the left paths, leading you to 11B and 22C.
+Step 6: You choose all of the right paths, leading you to 11Z and 22Z.
+So, in this example, you end up entirely on nodes that end in Z after 6 steps.
+
+Simultaneously start on every node that ends with A. How many steps does it take before you're only on nodes that end with Z?
+
+*/
+
+use std::collections::HashMap;
+use rayon::prelude::*;
+use aoc_core::{ParseError, Solution};
+use aoc_core::cycle::{self, Cycle};
+use aoc_core::parse::split_once;
+
+pub struct Node {
+   name: String,
+   left: String,
+   right: String
+}
+
+pub struct Map {
+   path: String,
+   nodes: HashMap<String, Node>
+}
+
+// a line like "AAA = (BBB, CCC)"
+fn load_node<'a>(input: &str, line: &'a str) -> Result<(&'a str, &'a str, &'a str), ParseError> {
+   let (node, next) = split_once(input, line, " = ")?;
+   let next = next.strip_prefix("(").ok_or_else(|| ParseError::at(input, next, "\"(\""))?;
+   let (left, right) = split_once(input, next, ", ")?;
+   let right = right.strip_suffix(")").ok_or_else(|| ParseError::at(input, &right[right.len()..], "\")\""))?;
+   Ok((node, left, right))
+}
+
+fn load_map(input: &str) -> Result<Map, ParseError> {
+   let mut ret =  Map {
+      path: String::from(""),
+      nodes: HashMap::new()
+   };
+
+   let mut lines = input.lines();
+   let path = lines.next().ok_or_else(|| ParseError::missing(input, "a path of L and R"))?;
+   if path.is_empty() {
+      return Err(ParseError::at(input, path, "a path of L and R"));
+   }
+   if let Some((i,c)) = path.char_indices().find(|&(_,c)| c != 'L' && c != 'R') {
+      return Err(ParseError::at(input, &path[i..i+c.len_utf8()], "L or R"));
+   }
+   ret.path = String::from(path);
+
+   let mut links = Vec::new();
+   for line in lines.filter(|l| !l.is_empty()) {
+      let (node, left, right) = load_node(input, line)?;
+      links.extend([left, right]);
+      ret.nodes.insert(node.to_string(), Node{name: node.to_string(), left: left.to_string(), right: right.to_string()});
+   }
+
+   // the walk would get lost on a node that is not in the network
+   if let Some(unknown) = links.into_iter().find(|n| !ret.nodes.contains_key(*n)) {
+      return Err(ParseError::at(input, unknown, "a node of the network"));
+   }
+
+   Ok(ret)
+}
+
+fn walk_part_one(map: &Map) -> Result<usize, ParseError> {
+   let mut steps = 0;
+   let path: Vec<char> = map.path.chars().collect();
+   let mut cur_node: &Node = map.nodes.get("AAA").ok_or_else(|| ParseError::whole("a node AAA", "none"))?;
+
+   loop {
+      //println!("entering {}", cur_node.name);
+      if cur_node.name == "ZZZ" {
+         break;
+      }
+      // past every node at every point of the path, the walk is going round a loop
+      if steps >= map.nodes.len() * path.len() {
+         return Err(ParseError::whole("a way from AAA to ZZZ", "a loop that never reaches ZZZ"));
+      }
+      
+      let next_move = path[steps % path.len()];
+      //println!("next move {}", next_move);
+      match next_move {
+         'L' => cur_node = &map.nodes[cur_node.left.as_str()],
+         'R' => cur_node = &map.nodes[cur_node.right.as_str()],
+         _ => panic!("forbidden move")
+      }
+      steps += 1;
+   }
+
+   Ok(steps)
+}
+
+/* ---------- part two ---------- */
+
+// I first tried brute force but after 2 hours I stopped the job and changed approach
+
+// a ghost that is back on a node at the same point of the path walks the same loop again,
+// it is on Z nodes at the same steps of every loop
+fn ghost_cycle(map: &Map, path: &[char], start: &str) -> (Cycle, Vec<usize>) {
+   let (cycle, states) = cycle::hashed((start, 0), |&(node, i)| {
+      let node = &map.nodes[node];
+      let next = match path[i] {
+         'L' => &node.left,
+         'R' => &node.right,
+         _ => panic!("forbidden move")
+      };
+      (next.as_str(), (i + 1) % path.len())
+   });
+
+   let on_z = states.iter()
+   .enumerate()
+   .filter(|(_, (node, _))| node.ends_with('Z'))
+   .map(|(step, _)| step)
+   .collect();
+   (cycle, on_z)
+}
+
+fn walk_part_two(map: &Map) -> Result<usize, ParseError> {
+   let path: Vec<char> = map.path.chars().collect();
+   let starting_positions: Vec<&str> = map.nodes.keys()
+   .filter(|k| k.ends_with('A'))
+   .map(|k| k.as_str())
+   .collect();
+   if starting_positions.is_empty() {
+      return Err(ParseError::whole("a node ending with A", "none"));
+   }
+
+   let ghosts: Vec<(Cycle, Vec<usize>)> = starting_positions
+   .par_iter()
+   .map(|start| ghost_cycle(map, &path, start))
+   .collect();
+
+   // all ghosts meet when their loops put them on Z nodes at the same step
+   cycle::align(&ghosts).ok_or_else(|| ParseError::whole("a step at which every ghost is on a Z node", "none"))
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+   type Input = Map;
+   type Answer1 = usize;
+   type Answer2 = usize;
+
+   fn parse(input: &str) -> Result<Map, ParseError> {
+      load_map(input)
+   }
+
+   fn part_one(map: &Map) -> usize {
+      Day8::try_part_one(map).unwrap_or_else(|e| panic!("{}", e))
+   }
+
+   fn part_two(map: &Map) -> usize {
+      Day8::try_part_two(map).unwrap_or_else(|e| panic!("{}", e))
+   }
+
+   // a network that parses may have no way to the end
+   fn try_part_one(map: &Map) -> Result<usize, ParseError> {
+      walk_part_one(map)
+   }
+
+   fn try_part_two(map: &Map) -> Result<usize, ParseError> {
+      walk_part_two(map)
+   }
+}
+
+#[test]
+fn test_1() {
+   let test_str = include_str!("../assets/example");
+   assert_eq!(2, Day8::part_one(&Day8::parse(test_str).unwrap()));
+}
+
+#[test]
+fn test_2() {
+   let input = Day8::parse(include_str!("../assets/test2")).unwrap();
+   assert_eq!(6, Day8::part_one(&input));
+}
+
+#[test]
+fn test_3() {
+   let input = Day8::parse(include_str!("../assets/test3")).unwrap();
+   assert_eq!(6, Day8::part_two(&input));
+}
+
+#[test]
+fn test_4() {
+   let e = Day8::parse("\n").map(|_| ()).unwrap_err();
+   assert_eq!((1, 1, "a path of L and R"), (e.line, e.column, e.expected.as_str()));
+
+   // AAA goes round without ZZZ, and no ghost starts
+   let input = Day8::parse("LR\n\nAAA = (BBB, AAA)\nBBB = (ZZZ, AAA)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+   assert_eq!("a way from AAA to ZZZ", Day8::try_part_one(&input).unwrap_err().expected);
+   assert_eq!("a node ending with A", Day8::try_part_two(&Day8::parse("L\n\nZZZ = (ZZZ, ZZZ)\n").unwrap()).unwrap_err().expected);
+   assert_eq!("a node AAA", Day8::try_part_one(&Day8::parse("L\n\nZZZ = (ZZZ, ZZZ)\n").unwrap()).unwrap_err().expected);
+   // one ghost is on Z every other step, the other never
+   let input = Day8::parse("L\n\n1A = (1Z, 1Z)\n1Z = (1A, 1A)\n2A = (2B, 2B)\n2B = (2A, 2A)\n").unwrap();
+   assert!(Day8::try_part_two(&input).is_err());
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use aoc_core::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = day13::Day13::parse(input);
+});